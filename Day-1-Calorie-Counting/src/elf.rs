@@ -0,0 +1,56 @@
+use serde::Serialize;
+
+use crate::Carrier;
+
+/// An elf and the calories of each item in its inventory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Elf {
+    /// Position of the elf in the input, counted from 0
+    pub index: usize,
+    /// Name given by the header of the inventory, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Calories of each item, in input order
+    pub items: Vec<u64>,
+    /// Calories of all the items combined
+    pub total: u64,
+}
+
+impl Elf {
+    /// Elf at `index` carrying `items`
+    ///
+    /// # Panics
+    ///
+    /// If the calories of the items don't fit in a `u64`, which [`Elves`]
+    /// reports as an error instead
+    ///
+    /// [`Elves`]: crate::Elves
+    pub fn new(index: usize, items: Vec<u64>) -> Self {
+        let total = items
+            .iter()
+            .try_fold(0u64, |total, &calories| total.checked_add(calories))
+            .expect("Calories overflow");
+        Self {
+            index,
+            name: None,
+            items,
+            total,
+        }
+    }
+
+    /// Names the elf `name`
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl From<&Elf> for Carrier {
+    fn from(elf: &Elf) -> Self {
+        Carrier {
+            index: elf.index,
+            name: elf.name.clone(),
+            calories: elf.total,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::CalorieCounting;
+
+impl Generator for CalorieCounting {
+    /// Generates the inventories of `size` elves, each carrying 1 to 10 items
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let items: Vec<String> = (0..rng.range(1..=10))
+                    .map(|_| rng.range(1_000..=60_000).to_string())
+                    .collect();
+                items.join("\n")
+            })
+            .collect();
+
+        elves.join("\n\n") + "\n"
+    }
+}
@@ -0,0 +1,116 @@
+//! Day 1: Calorie Counting
+//!
+//! Finds the elves carrying the most calories from their inventories, where
+//! each elf's items are listed one per line and elves are separated by a
+//! blank line.
+
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::{
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde_json::{json, Value};
+
+mod elf;
+mod generate;
+mod ranking;
+mod rebalance;
+mod stats;
+mod stream;
+mod subset;
+mod summary;
+mod trace;
+
+pub use elf::Elf;
+pub use ranking::{Carrier, Offer, Rank, Ranking};
+pub use rebalance::{rebalance, Move, Plan, EXACT_ITEMS};
+pub use stats::{percentile, Bucket, Histogram, Percentile, Stats, PERCENTILES};
+pub use stream::{merge_elves, read_elves, Elves, ReadError};
+pub use subset::{closest_subset, Subset, SubsetError, DEFAULT_MEMORY};
+pub use summary::Summary;
+pub use trace::Event;
+
+/// [`Solution`] for day 1
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    /// Inventory of each elf, in input order
+    type Input = Vec<Elf>;
+
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        // Reading from memory can't fail, so every error has a position
+        read_elves(input.as_bytes()).map_err(|e| {
+            e.diagnostic()
+                .unwrap_or_else(|| Diagnostic::at_end(e.to_string(), ""))
+        })
+    }
+
+    /// Returns the most calories carried by a single elf
+    fn part1(elves: &Self::Input) -> Result<u64> {
+        let top = top_carriers(elves, 1);
+        Ok(top.first().map_or(0, |carrier| carrier.calories))
+    }
+
+    /// Returns the calories carried by the top three elves combined
+    fn part2(elves: &Self::Input) -> Result<u128> {
+        Ok(sum_calories(&top_carriers(elves, 3)))
+    }
+
+    /// Number of elves, the top three carriers, most first, and statistics
+    /// of the calories carried
+    fn details(elves: &Self::Input) -> Value {
+        json!({
+            "elves": elves.len(),
+            "top_carriers": top_carriers(elves, 3),
+            "stats": Stats::of(elves),
+        })
+    }
+}
+
+/// Returns the `count` elves carrying the most calories, most first
+pub fn top_carriers(elves: &[Elf], count: usize) -> Vec<Carrier> {
+    rank(elves, Rank::Top, count, &mut NoTrace)
+}
+
+/// Returns the `count` elves carrying the fewest calories, fewest first
+pub fn bottom_carriers(elves: &[Elf], count: usize) -> Vec<Carrier> {
+    rank(elves, Rank::Bottom, count, &mut NoTrace)
+}
+
+/// Calories carried by `carriers` combined, which can't overflow unlike a
+/// sum of `u64`
+pub fn sum_calories(carriers: &[Carrier]) -> u128 {
+    carriers
+        .iter()
+        .map(|carrier| u128::from(carrier.calories))
+        .sum()
+}
+
+/// Ranks the elves keeping `count` of them at the `rank` end, sending every
+/// elf and every change of the ranking to `tracer`
+fn rank(elves: &[Elf], rank: Rank, count: usize, tracer: &mut impl Tracer<Event>) -> Vec<Carrier> {
+    let mut ranking = Ranking::new(rank, count);
+
+    for elf in elves {
+        let (index, calories) = (elf.index, elf.total);
+        tracer.step(Event::Elf { index, calories });
+
+        if let Offer::Joined(replaced) = ranking.offer(Carrier::from(elf)) {
+            tracer.step(Event::Ranked {
+                index,
+                calories,
+                replaced: replaced.map(|carrier| carrier.index),
+            });
+        }
+    }
+
+    ranking.into_carriers()
+}
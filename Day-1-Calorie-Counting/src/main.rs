@@ -1,64 +1,231 @@
-use std::fs;
+use std::{
+    env,
+    fmt::Display,
+    io::BufRead,
+    path::{Path, PathBuf},
+    process::exit,
+    str::FromStr,
+};
 
-fn main() {
-    let input = fs::read_to_string("src/input.txt").expect("Error reading input.txt");
+use aoc_common::{
+    input::{self, Args},
+    report,
+};
+use day_1_calorie_counting::{
+    closest_subset, merge_elves, read_elves, rebalance, sum_calories, CalorieCounting, Carrier,
+    Elf, Plan, Subset, Summary, DEFAULT_MEMORY,
+};
+use rayon::ThreadPoolBuilder;
+
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// Chunks given to each thread with `--threads`, so a slow chunk doesn't
+/// leave the other threads idle
+const CHUNKS_PER_THREAD: usize = 4;
 
-    let max_count = 3;
-    let mut max_carriers = Vec::with_capacity(max_count);
+/// Bytes read at once with `--threads`, before splitting them into chunks
+const BATCH: usize = 64 * 1024 * 1024;
+
+fn main() {
+    let options = [
+        "top",
+        "bottom",
+        "histogram",
+        "threads",
+        "target",
+        "elf",
+        "memory",
+        "merge",
+    ];
+    let args = Args::parse_with(env::args_os().skip(1), &options, &["rebalance"])
+        .unwrap_or_else(|e| fail(e));
+    let default = Path::new(DEFAULT_INPUT);
 
-    let mut max_calories = 0;
-    let mut current_elf = 0;
+    let target: Option<u64> = option(&args, "target");
+    let elf: Option<String> = option(&args, "elf");
+    let memory = match option::<usize>(&args, "memory") {
+        None => DEFAULT_MEMORY,
+        Some(mib) => mib
+            .checked_mul(1 << 20)
+            .unwrap_or_else(|| fail(format_args!("--memory {mib} MiB is too large"))),
+    };
+    let merged: Vec<PathBuf> = args.values("merge").unwrap_or_else(|e| fail(e));
 
-    for line in input.lines() {
-        if line.is_empty() {
-            if current_elf > max_calories {
-                max_calories = current_elf;
-            }
-            push_if_top(&mut max_carriers, current_elf, max_count);
-            current_elf = 0;
+    if args.json || args.flag("rebalance") || elf.is_some() {
+        // These need every item, not only the summary
+        let elves = if merged.is_empty() {
+            let reader = args.open_input(default).unwrap_or_else(|e| fail(e));
+            read_elves(reader)
         } else {
-            current_elf += line.trim().parse::<u32>().expect("Invalid number.")
+            merge_elves(open_inputs(&args, default, &merged))
         }
+        .unwrap_or_else(|e| fail(e));
+
+        if args.json {
+            report::print::<CalorieCounting>(&elves);
+        } else if args.flag("rebalance") {
+            print_plan(&rebalance(&elves));
+        } else if let Some(elf) = elf {
+            let Some(target) = target else {
+                fail("--elf needs a --target");
+            };
+            let Some(carrier) = find_elf(&elves, &elf) else {
+                fail(format_args!("No elf {elf}, there are {}", elves.len()));
+            };
+            let subset = closest_subset(&carrier.items, target, memory).unwrap_or_else(|e| fail(e));
+            let labels = subset
+                .indices
+                .iter()
+                .map(|index| (index + 1).to_string())
+                .collect();
+            let what = format!("Items of {}", Carrier::from(carrier));
+            print_subset(&what, labels, &subset, target);
+        }
+        return;
     }
-    if current_elf > max_calories {
-        max_calories = current_elf;
+
+    let top = option(&args, "top").unwrap_or(3);
+    let bottom: Option<usize> = option(&args, "bottom");
+    let histogram: Option<usize> = option(&args, "histogram");
+
+    let summary = match option::<usize>(&args, "threads") {
+        None if !merged.is_empty() => Summary::merge(
+            open_inputs(&args, default, &merged),
+            top,
+            bottom.unwrap_or(0),
+        ),
+        // Streamed so huge inputs are never held in memory as text
+        None => {
+            let reader = args.open_input(default).unwrap_or_else(|e| fail(e));
+            Summary::read(reader, top, bottom.unwrap_or(0))
+        }
+        Some(_) if !merged.is_empty() => fail("--threads reads a single input, without --merge"),
+        Some(threads) => {
+            ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build_global()
+                .unwrap_or_else(|e| fail(e));
+            let chunks = rayon::current_num_threads() * CHUNKS_PER_THREAD;
+            let reader = args.open_input(default).unwrap_or_else(|e| fail(e));
+            Summary::read_parallel(reader, top, bottom.unwrap_or(0), chunks, BATCH)
+        }
     }
-    push_if_top(&mut max_carriers, current_elf, max_count);
+    .unwrap_or_else(|e| fail(e));
 
-    println!("{}", max_calories);
+    println!("{}", summary.totals.iter().max().unwrap_or(&0));
 
-    println!("Top {} elves: {:#?}", max_count, max_carriers);
-    println!("Sum of top elves: {}", max_carriers.iter().sum::<u32>());
-}
+    println!("Top {top} elves:");
+    print_carriers(&summary.top);
+    println!("Sum of top elves: {}", sum_calories(&summary.top));
+
+    if let Some(bottom) = bottom {
+        println!("Bottom {bottom} elves:");
+        print_carriers(&summary.bottom);
+    }
 
-/// Replaces the minimum value in the vector if `num` is greater than it
-/// and the array has reached its capacity
-fn push_if_top(arr: &mut Vec<u32>, num: u32, capacity: usize) {
-    if arr.len() >= capacity {
-        let min_index = find_min_index(&arr);
-        if num > arr[min_index] {
-            arr[min_index] = num;
+    if let Some(buckets) = histogram {
+        if let Some(stats) = summary.stats() {
+            print!("{stats}");
         }
+        print!("{}", summary.histogram(buckets));
+    }
+
+    if let Some(target) = target {
+        let subset = closest_subset(&summary.totals, target, memory).unwrap_or_else(|e| fail(e));
+        let labels = subset
+            .indices
+            .iter()
+            .map(|&index| {
+                let carrier = Carrier {
+                    index,
+                    name: summary.names[index].clone(),
+                    calories: summary.totals[index],
+                };
+                carrier.to_string()
+            })
+            .collect();
+        print_subset("Elves", labels, &subset, target);
+    }
+}
+
+fn print_carriers(carriers: &[Carrier]) {
+    for (place, carrier) in carriers.iter().enumerate() {
+        println!("  {}. {carrier}: {} calories", place + 1, carrier.calories);
+    }
+}
+
+fn print_plan(plan: &Plan) {
+    let quality = if plan.optimal {
+        "optimal"
     } else {
-        // Array is empty
-        arr.push(num);
+        "best found"
+    };
+    println!(
+        "Most calories carried: {} -> {} ({quality})",
+        plan.before, plan.after
+    );
+    println!("{} moves:", plan.moves.len());
+    for (step, item) in plan.moves.iter().enumerate() {
+        println!("  {}. {item}", step + 1);
     }
 }
 
-/// Returns the index of the minimum value in the vector
-/// If the minimum values is present more than once, the first index will be returned
-/// Panics if vector is empty
-fn find_min_index(arr: &Vec<u32>) -> usize {
-    assert!(arr.len() > 0, "Array is empty");
+/// Opens the input and the inputs given with `--merge`, named by their path
+fn open_inputs(args: &Args, default: &Path, merged: &[PathBuf]) -> Vec<(String, Box<dyn BufRead>)> {
+    let first = args
+        .input_path(default)
+        .unwrap_or_else(|| default.to_path_buf());
+    let first = (
+        first.display().to_string(),
+        args.open_input(default).unwrap_or_else(|e| fail(e)),
+    );
 
-    let mut min_index = 0;
-    let mut min_val = arr[0];
+    let rest = merged.iter().map(|path| {
+        let reader = input::open_input(Some(path), default).unwrap_or_else(|e| fail(e));
+        (path.display().to_string(), reader)
+    });
+    [first].into_iter().chain(rest).collect()
+}
 
-    for (i, num) in arr.iter().enumerate() {
-        if *num < min_val {
-            min_val = *num;
-            min_index = i;
-        }
-    }
-    min_index
+/// Elf named `elf`, or else numbered `elf` counting from 1
+fn find_elf<'a>(elves: &'a [Elf], elf: &str) -> Option<&'a Elf> {
+    elves
+        .iter()
+        .find(|candidate| candidate.name.as_deref() == Some(elf))
+        .or_else(|| {
+            let number: usize = elf.parse().ok()?;
+            elves.get(number.checked_sub(1)?)
+        })
+}
+
+/// Prints the `labels` of the values in `subset` after `what`
+fn print_subset(what: &str, labels: Vec<String>, subset: &Subset, target: u64) {
+    let closeness = if subset.exact {
+        "exactly the target"
+    } else {
+        "closest below the target"
+    };
+    println!(
+        "{what} summing to {target}: {} ({} calories, {closeness})",
+        if labels.is_empty() {
+            "none".to_string()
+        } else {
+            labels.join(", ")
+        },
+        subset.sum
+    );
+}
+
+/// Value of the option `name`, exiting when it is invalid
+fn option<T>(args: &Args, name: &str) -> Option<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    args.option(name).unwrap_or_else(|e| fail(e))
+}
+
+fn fail(error: impl Display) -> ! {
+    eprintln!("{error:#}");
+    exit(1);
 }
@@ -0,0 +1,116 @@
+use std::{cmp::Ordering, fmt};
+
+use aoc_common::top::{Pushed, TopK};
+
+use serde::Serialize;
+
+/// An elf and the calories it carries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Carrier {
+    /// Position of the elf in the input, counted from 0
+    pub index: usize,
+    /// Name of the elf, when the input gives one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Calories the elf carries
+    pub calories: u64,
+}
+
+impl fmt::Display for Carrier {
+    /// Name of the elf, or its position counted from 1
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "Elf {}", self.index + 1),
+        }
+    }
+}
+
+/// End of the ranking that is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rank {
+    /// Elves carrying the most calories, most first
+    Top,
+    /// Elves carrying the fewest calories, fewest first
+    Bottom,
+}
+
+/// What happened to an elf offered to a [`Ranking`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Offer {
+    /// The elf does not make the ranking
+    Rejected,
+    /// The elf joined the ranking, pushing out the returned elf when the
+    /// ranking was full
+    Joined(Option<Carrier>),
+}
+
+/// Carrier ordered by how high it ranks, so the collector keeps the highest
+#[derive(Debug)]
+struct Ranked {
+    rank: Rank,
+    carrier: Carrier,
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (&self.carrier, &other.carrier);
+        let calories = match self.rank {
+            Rank::Top => a.calories.cmp(&b.calories),
+            Rank::Bottom => b.calories.cmp(&a.calories),
+        };
+        // The earlier elf ranks higher
+        calories.then(b.index.cmp(&a.index))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+/// The `count` elves at one end of the ranking. Elves carrying the same
+/// calories are ranked in input order, so the earlier elf ranks higher
+#[derive(Debug)]
+pub struct Ranking {
+    rank: Rank,
+    top: TopK<Ranked>,
+}
+
+impl Ranking {
+    /// Empty ranking keeping `count` elves
+    pub fn new(rank: Rank, count: usize) -> Self {
+        Self {
+            rank,
+            top: TopK::new(count),
+        }
+    }
+
+    /// Adds `carrier` if it ranks among the `count` first elves
+    pub fn offer(&mut self, carrier: Carrier) -> Offer {
+        let rank = self.rank;
+        match self.top.push(Ranked { rank, carrier }) {
+            Pushed::Kept => Offer::Joined(None),
+            Pushed::Replaced(ranked) => Offer::Joined(Some(ranked.carrier)),
+            Pushed::Rejected(_) => Offer::Rejected,
+        }
+    }
+
+    /// Takes the ranked elves, highest first
+    pub fn into_carriers(self) -> Vec<Carrier> {
+        self.top
+            .into_sorted_vec()
+            .into_iter()
+            .map(|ranked| ranked.carrier)
+            .collect()
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt,
+};
+
+use serde::Serialize;
+
+use crate::Elf;
+
+/// Most items the exact solver is used for, larger inputs are only balanced
+/// with the greedy heuristic
+pub const EXACT_ITEMS: usize = 20;
+
+/// Assignments the exact solver tries before settling for the best found
+const EXACT_BUDGET: usize = 5_000_000;
+
+/// An item handed from one elf to another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Move {
+    /// Elf giving the item, counted from 0
+    pub from: usize,
+    /// Name of the elf giving the item, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_name: Option<String>,
+    /// Position of the item in the inventory of `from`, counted from 0
+    pub item: usize,
+    /// Calories of the item
+    pub calories: u64,
+    /// Elf receiving the item, counted from 0
+    pub to: usize,
+    /// Name of the elf receiving the item, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_name: Option<String>,
+}
+
+impl fmt::Display for Move {
+    /// Elves by name, or by position counted from 1
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elf = |index: usize, name: &Option<String>| match name {
+            Some(name) => name.clone(),
+            None => format!("Elf {}", index + 1),
+        };
+        write!(
+            f,
+            "Item {} of {} ({} calories) to {}",
+            self.item + 1,
+            elf(self.from, &self.from_name),
+            self.calories,
+            elf(self.to, &self.to_name)
+        )
+    }
+}
+
+/// Items to move between elves so the most loaded elf carries as little as
+/// possible
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Plan {
+    /// Most calories carried by an elf before the moves
+    pub before: u64,
+    /// Most calories carried by an elf after the moves
+    pub after: u64,
+    /// Whether no plan can do better than `after`
+    pub optimal: bool,
+    /// Items to move, by elf giving them and then by position
+    pub moves: Vec<Move>,
+}
+
+/// An item and the elf carrying it
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    calories: u64,
+    owner: usize,
+    position: usize,
+}
+
+/// Plans the moves that minimize the most calories carried by an elf.
+///
+/// Inputs of at most [`EXACT_ITEMS`] items are solved exactly with a branch
+/// and bound search. Larger ones are balanced by handing the largest items
+/// first to the least loaded elf, which is at most a third worse than the
+/// best plan. Among plans as good, the one moving fewer items is preferred,
+/// and nothing moves when the elves can't do better than they already do.
+/// Elves carrying more than the planned most calories then first try to hand
+/// just enough items to the elves with the most room left
+pub fn rebalance(elves: &[Elf]) -> Plan {
+    let mut items: Vec<Item> = elves
+        .iter()
+        .enumerate()
+        .flat_map(|(owner, elf)| {
+            elf.items
+                .iter()
+                .enumerate()
+                .map(move |(position, &calories)| Item {
+                    calories,
+                    owner,
+                    position,
+                })
+        })
+        .collect();
+    items.sort_by_key(|item| Reverse(item.calories));
+
+    let bins = elves.len();
+    let before = elves.iter().map(|elf| elf.total).max().unwrap_or(0);
+    let lower = lower_bound(&items, bins);
+
+    let (mut assignment, mut after) = greedy(&items, bins);
+    let mut optimal = after == lower;
+    if !optimal && items.len() <= EXACT_ITEMS {
+        let search = exact(&items, bins, lower, after);
+        optimal = search.complete;
+        if let Some((found, max)) = search.best {
+            (assignment, after) = (found, max);
+        }
+    }
+
+    if after >= u128::from(before) {
+        // The elves can't do better than they already do
+        return Plan {
+            before,
+            after: before,
+            optimal: optimal || lower == u128::from(before),
+            moves: Vec::new(),
+        };
+    }
+
+    // Reaching the same target from the current inventories usually takes
+    // far fewer moves than handing out the items from scratch
+    let partition = moves(&items, &assignment, bins);
+    let (after, mut moves) = match repair(elves, after) {
+        Some((max, repaired)) if (max, repaired.len()) < (after, partition.len()) => {
+            (max, repaired)
+        }
+        _ => (after, partition),
+    };
+    for step in &mut moves {
+        step.from_name = elves[step.from].name.clone();
+        step.to_name = elves[step.to].name.clone();
+    }
+
+    Plan {
+        before,
+        after: after as u64,
+        optimal,
+        moves,
+    }
+}
+
+/// Most calories an elf carries in any plan: an even share of all calories,
+/// and at least the largest item
+fn lower_bound(items: &[Item], bins: usize) -> u128 {
+    let total: u128 = items.iter().map(|item| u128::from(item.calories)).sum();
+    let largest = items.first().map_or(0, |item| u128::from(item.calories));
+    total.div_ceil(bins.max(1) as u128).max(largest)
+}
+
+/// Hands the items, largest first, to the least loaded bin. Returns the bin of
+/// each item and the most loaded bin
+fn greedy(items: &[Item], bins: usize) -> (Vec<usize>, u128) {
+    let mut loads: BinaryHeap<Reverse<(u128, usize)>> =
+        (0..bins).map(|bin| Reverse((0, bin))).collect();
+    let mut assignment = Vec::with_capacity(items.len());
+
+    for item in items {
+        let Some(Reverse((load, bin))) = loads.pop() else {
+            break;
+        };
+        assignment.push(bin);
+        loads.push(Reverse((load + u128::from(item.calories), bin)));
+    }
+
+    let max = loads.into_iter().map(|Reverse((load, _))| load).max();
+    (assignment, max.unwrap_or(0))
+}
+
+/// Outcome of the exact search
+struct Search {
+    /// Best plan strictly better than the starting bound, if any
+    best: Option<(Vec<usize>, u128)>,
+    /// Whether every plan was considered, so the best one is optimal
+    complete: bool,
+}
+
+/// Looks for the plan with the least loaded most loaded bin, better than
+/// `bound`, stopping early at `lower`
+fn exact(items: &[Item], bins: usize, lower: u128, bound: u128) -> Search {
+    struct State<'a> {
+        items: &'a [Item],
+        /// Calories of the items from each index on
+        remaining: Vec<u128>,
+        lower: u128,
+        loads: Vec<u128>,
+        assignment: Vec<usize>,
+        bound: u128,
+        best: Option<(Vec<usize>, u128)>,
+        budget: usize,
+    }
+
+    fn assign(state: &mut State, index: usize, max: u128) {
+        if state.budget == 0 || state.bound == state.lower {
+            return;
+        }
+        state.budget -= 1;
+
+        let Some(item) = state.items.get(index) else {
+            state.bound = max;
+            state.best = Some((state.assignment.clone(), max));
+            return;
+        };
+
+        // The items left must fit below the bound, which may have dropped
+        // below the loads since they were assigned
+        if max >= state.bound {
+            return;
+        }
+        // Room too small for the smallest item left is lost
+        let smallest = state
+            .items
+            .last()
+            .map_or(0, |item| u128::from(item.calories));
+        let room: u128 = state
+            .loads
+            .iter()
+            .map(|load| state.bound - 1 - load)
+            .filter(|&room| room >= smallest)
+            .sum();
+        if state.remaining[index] > room {
+            return;
+        }
+
+        // Least loaded bins first, to find good plans early
+        let mut bins: Vec<usize> = (0..state.loads.len()).collect();
+        bins.sort_by_key(|&bin| state.loads[bin]);
+
+        for (i, &bin) in bins.iter().enumerate() {
+            let load = state.loads[bin];
+            // Bins with the same load lead to the same plans
+            if i > 0 && state.loads[bins[i - 1]] == load {
+                continue;
+            }
+            let load = load + u128::from(item.calories);
+            if load >= state.bound {
+                continue;
+            }
+
+            state.loads[bin] = load;
+            state.assignment.push(bin);
+            assign(state, index + 1, max.max(load));
+            state.assignment.pop();
+            state.loads[bin] -= u128::from(item.calories);
+        }
+    }
+
+    let mut remaining: Vec<u128> = items
+        .iter()
+        .rev()
+        .scan(0, |sum, item| {
+            *sum += u128::from(item.calories);
+            Some(*sum)
+        })
+        .collect();
+    remaining.reverse();
+    remaining.push(0);
+
+    let mut state = State {
+        items,
+        remaining,
+        lower,
+        loads: vec![0; bins],
+        assignment: Vec::with_capacity(items.len()),
+        bound,
+        best: None,
+        budget: EXACT_BUDGET,
+    };
+    assign(&mut state, 0, 0);
+
+    Search {
+        complete: state.budget > 0,
+        best: state.best,
+    }
+}
+
+/// Gives each bin to an elf so that as many items as possible stay with their
+/// elf, and lists the items that still have to move
+fn moves(items: &[Item], assignment: &[usize], bins: usize) -> Vec<Move> {
+    let mut kept: HashMap<(usize, usize), (usize, u64)> = HashMap::new();
+    for (item, &bin) in items.iter().zip(assignment) {
+        let (count, calories) = kept.entry((bin, item.owner)).or_default();
+        *count += 1;
+        *calories += item.calories;
+    }
+
+    // Greedily pairs the bins and elves sharing the most items
+    let mut pairs: Vec<((usize, usize), (usize, u64))> = kept.into_iter().collect();
+    pairs.sort_by_key(|&(pair, (count, calories))| (Reverse(count), Reverse(calories), pair));
+
+    let mut elf_of_bin = vec![None; bins];
+    let mut taken = vec![false; bins];
+    for ((bin, elf), _) in pairs {
+        if elf_of_bin[bin].is_none() && !taken[elf] {
+            elf_of_bin[bin] = Some(elf);
+            taken[elf] = true;
+        }
+    }
+    let mut free = (0..bins).filter(|&elf| !taken[elf]);
+    let elf_of_bin: Vec<usize> = elf_of_bin
+        .into_iter()
+        .map(|elf| elf.or_else(|| free.next()).expect("As many elves as bins"))
+        .collect();
+
+    let mut moves: Vec<Move> = items
+        .iter()
+        .zip(assignment)
+        .filter(|(item, &bin)| elf_of_bin[bin] != item.owner)
+        .map(|(item, &bin)| Move {
+            from: item.owner,
+            from_name: None,
+            item: item.position,
+            calories: item.calories,
+            to: elf_of_bin[bin],
+            to_name: None,
+        })
+        .collect();
+    moves.sort_by_key(|m| (m.from, m.item));
+    moves
+}
+
+/// Moves items off the elves carrying more than `target`, the smallest item
+/// covering the excess or else the largest ones, to the elves with the most
+/// room left. Returns the moves and the most loaded elf, `None` when an item
+/// fits nowhere
+fn repair(elves: &[Elf], target: u128) -> Option<(u128, Vec<Move>)> {
+    let mut loads: Vec<u128> = elves.iter().map(|elf| u128::from(elf.total)).collect();
+    let mut shed = Vec::new();
+
+    for (owner, elf) in elves.iter().enumerate() {
+        let mut excess = loads[owner].saturating_sub(target);
+        let mut kept: Vec<(usize, u64)> = elf.items.iter().copied().enumerate().collect();
+        kept.sort_by_key(|&(_, calories)| Reverse(calories));
+
+        while excess > 0 && !kept.is_empty() {
+            let pick = kept
+                .iter()
+                .rposition(|&(_, calories)| u128::from(calories) >= excess)
+                .unwrap_or(0);
+            let (position, calories) = kept.remove(pick);
+            excess = excess.saturating_sub(u128::from(calories));
+            loads[owner] -= u128::from(calories);
+            shed.push(Item {
+                calories,
+                owner,
+                position,
+            });
+        }
+    }
+
+    shed.sort_by_key(|item| Reverse(item.calories));
+    let mut room: BinaryHeap<(u128, Reverse<usize>)> = loads
+        .iter()
+        .enumerate()
+        .map(|(elf, &load)| (target.saturating_sub(load), Reverse(elf)))
+        .collect();
+    let mut moves = Vec::with_capacity(shed.len());
+
+    for item in shed {
+        let (free, Reverse(elf)) = room.pop()?;
+        let calories = u128::from(item.calories);
+        if free < calories {
+            return None;
+        }
+        loads[elf] += calories;
+        room.push((free - calories, Reverse(elf)));
+        moves.push(Move {
+            from: item.owner,
+            from_name: None,
+            item: item.position,
+            calories: item.calories,
+            to: elf,
+            to_name: None,
+        });
+    }
+
+    moves.sort_by_key(|m| (m.from, m.item));
+    Some((loads.into_iter().max().unwrap_or(0), moves))
+}
@@ -0,0 +1,208 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::Elf;
+
+/// Percentiles listed in [`Stats`]
+pub const PERCENTILES: [u8; 4] = [25, 75, 90, 99];
+
+/// Longest bar of a [`Histogram`], in characters
+const BAR_WIDTH: usize = 40;
+
+/// Summary of the calories carried by the elves
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    /// Number of elves
+    pub elves: usize,
+    /// Number of items carried by all the elves
+    pub items: usize,
+    /// Fewest calories carried by an elf
+    pub min: u64,
+    /// Most calories carried by an elf
+    pub max: u64,
+    /// Mean of the calories carried by each elf
+    pub mean: f64,
+    /// Median of the calories carried by each elf
+    pub median: f64,
+    /// Population standard deviation of the calories carried by each elf
+    pub std_dev: f64,
+    /// Calories at each of the [`PERCENTILES`]
+    pub percentiles: Vec<Percentile>,
+}
+
+/// Calories below which `rank` percent of the elves fall
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Percentile {
+    /// Percentile, from 0 to 100
+    pub rank: u8,
+    /// Calories at that percentile
+    pub calories: f64,
+}
+
+impl Stats {
+    /// Summarizes `elves`, `None` when there are none
+    pub fn of(elves: &[Elf]) -> Option<Self> {
+        let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+        let items = elves.iter().map(|elf| elf.items.len()).sum();
+        Self::of_totals(totals, items)
+    }
+
+    /// Summarizes the calories carried by each elf, `items` in all, `None`
+    /// when there are no elves
+    pub fn of_totals(mut totals: Vec<u64>, items: usize) -> Option<Self> {
+        totals.sort_unstable();
+
+        let count = totals.len() as f64;
+        let mean = totals.iter().map(|&total| total as f64).sum::<f64>() / count;
+        let variance = totals
+            .iter()
+            .map(|&total| (total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Some(Self {
+            elves: totals.len(),
+            items,
+            min: *totals.first()?,
+            max: *totals.last()?,
+            mean,
+            median: percentile(&totals, 50),
+            std_dev: variance.sqrt(),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&rank| Percentile {
+                    rank,
+                    calories: percentile(&totals, rank),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Items: {}", self.items)?;
+        writeln!(f, "Min: {}", self.min)?;
+        writeln!(f, "Max: {}", self.max)?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        writeln!(f, "Standard deviation: {:.1}", self.std_dev)?;
+        for percentile in &self.percentiles {
+            writeln!(f, "P{}: {:.1}", percentile.rank, percentile.calories)?;
+        }
+        Ok(())
+    }
+}
+
+/// Value at `rank` percent of `sorted`, interpolating linearly between the
+/// two closest values
+///
+/// # Panics
+///
+/// If `sorted` is empty
+pub fn percentile(sorted: &[u64], rank: u8) -> f64 {
+    let position = f64::from(rank.min(100)) / 100.0 * (sorted.len() - 1) as f64;
+    let below = position.floor() as usize;
+    let above = position.ceil() as usize;
+    let fraction = position - below as f64;
+
+    let (below, above) = (sorted[below] as f64, sorted[above] as f64);
+    below + fraction * (above - below)
+}
+
+/// Number of elves carrying calories between `from` and `to`, inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    /// Fewest calories in the bucket
+    pub from: u64,
+    /// Most calories in the bucket
+    pub to: u64,
+    /// Number of elves in the bucket
+    pub count: usize,
+}
+
+/// Elves counted in buckets of equal width spanning their totals, printed as
+/// one bar per bucket:
+///
+/// ```text
+///  4000- 9000 | ######################################## 2
+///  9001-14001 | ######################################## 2
+/// 14002-19002 |  0
+/// 19003-24000 | #################### 1
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Histogram {
+    /// Buckets, fewest calories first
+    pub buckets: Vec<Bucket>,
+}
+
+impl Histogram {
+    /// Counts `elves` in at most `buckets` buckets, none when either is empty
+    pub fn of(elves: &[Elf], buckets: usize) -> Self {
+        let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+        Self::of_totals(&totals, buckets)
+    }
+
+    /// Counts the calories carried by each elf in at most `buckets` buckets
+    pub fn of_totals(totals: &[u64], buckets: usize) -> Self {
+        let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+            return Self { buckets: vec![] };
+        };
+        if buckets == 0 {
+            return Self { buckets: vec![] };
+        }
+
+        // Wider than `u64` when the totals span every `u64`
+        let span = u128::from(max - min) + 1;
+        let width = span.div_ceil(buckets as u128);
+        let mut counts = vec![0; span.div_ceil(width) as usize];
+        for &total in totals {
+            counts[(u128::from(total - min) / width) as usize] += 1;
+        }
+
+        let buckets = counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let from = u128::from(min) + i as u128 * width;
+                let to = (from + width - 1).min(u128::from(max));
+                Bucket {
+                    from: from as u64,
+                    to: to as u64,
+                    count,
+                }
+            })
+            .collect();
+
+        Self { buckets }
+    }
+}
+
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self
+            .buckets
+            .last()
+            .map_or(1, |bucket| bucket.to.to_string().len());
+        let most = self.buckets.iter().map(|bucket| bucket.count).max();
+
+        for bucket in &self.buckets {
+            // Any elf at all shows at least one character
+            let bar = match most {
+                Some(most) if bucket.count > 0 => (bucket.count * BAR_WIDTH / most).max(1),
+                _ => 0,
+            };
+            writeln!(
+                f,
+                "{:>digits$}-{:>digits$} | {} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.count
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    io::{self, BufRead},
+    num::IntErrorKind,
+};
+
+use aoc_common::{parse::Line, Diagnostic};
+
+use crate::Elf;
+
+/// Error reading the elves from a stream
+#[derive(Debug)]
+pub enum ReadError {
+    /// The stream could not be read
+    Io(io::Error),
+    /// A line is not a number of calories
+    Invalid(Diagnostic),
+    /// The calories carried by an elf, or a single item, don't fit in a `u64`
+    Overflow {
+        /// Position of the elf in the input, counted from 0
+        elf: usize,
+        /// Line of the item that overflowed, starting at 1
+        line: usize,
+        /// Text of that line
+        source_line: String,
+    },
+    /// Two elves have the same name
+    Duplicate {
+        /// Name of the elves
+        name: String,
+        /// Line of the second header with that name, starting at 1
+        line: usize,
+        /// Text of that line
+        source_line: String,
+        /// Line of the first header with that name, starting at 1
+        first_line: usize,
+        /// Input of the first header, for merged inputs
+        first_input: Option<String>,
+    },
+    /// Error in one of several merged inputs
+    In {
+        /// Name of the input
+        input: String,
+        /// Error found in it
+        error: Box<ReadError>,
+    },
+}
+
+impl std::error::Error for ReadError {}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => return write!(f, "Could not read the input: {e}"),
+            Self::In { input, error } => return write!(f, "In {input}: {error}"),
+            _ => {}
+        }
+        match self.diagnostic() {
+            Some(diagnostic) => write!(f, "{diagnostic}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl ReadError {
+    /// Position of the error in its input, `None` for I/O errors
+    pub fn diagnostic(&self) -> Option<Diagnostic> {
+        match self {
+            Self::Io(_) => None,
+            Self::In { error, .. } => error.diagnostic(),
+            Self::Invalid(diagnostic) => Some(diagnostic.clone()),
+            Self::Overflow {
+                elf,
+                line,
+                source_line,
+            } => {
+                let text = source_line.trim();
+                let message = format!("Calories carried by elf {} overflow", elf + 1);
+                Some(Line::new(*line, source_line).error(message, text))
+            }
+            Self::Duplicate {
+                name,
+                line,
+                source_line,
+                first_line,
+                first_input,
+            } => {
+                let mut message = format!("Elf \"{name}\" is already named on line {first_line}");
+                if let Some(input) = first_input {
+                    message += &format!(" of {input}");
+                }
+                Some(Line::new(*line, source_line).error(message, name))
+            }
+        }
+    }
+}
+
+/// Reads elves one at a time from a stream of inventories, keeping only the
+/// current elf and the names seen in memory.
+///
+/// Lines may end with `\n` or `\r\n`, and any run of blank lines separates two
+/// elves. A line starting with `#` names the elf when it is the first line of
+/// its group, as in `# Alice`, and is a comment anywhere else; groups without
+/// items are only comments. Two elves with the same name are an error.
+/// Totals are added with checked arithmetic, so inventories too large for a
+/// `u64` give a [`ReadError::Overflow`] instead of a wrong answer. The
+/// iterator stops after the first error
+pub struct Elves<R> {
+    inputs: VecDeque<Input<R>>,
+    buffer: String,
+    /// Lines read in the current input
+    line: usize,
+    /// Lines read in the previous inputs
+    lines: usize,
+    index: usize,
+    names: Names,
+    failed: bool,
+}
+
+/// Line and input of the header of each named elf
+pub(crate) type Names = HashMap<String, (usize, Option<String>)>;
+
+/// An input of [`Elves`], named when several are merged
+struct Input<R> {
+    name: Option<String>,
+    reader: R,
+}
+
+/// Header naming the elf being read
+struct Header {
+    name: String,
+    line: usize,
+    text: String,
+}
+
+impl<R: BufRead> Elves<R> {
+    /// Reads the elves of `reader`
+    pub fn new(reader: R) -> Self {
+        Self::from_inputs(vec![Input { name: None, reader }])
+    }
+
+    /// Reads the elves of each named input in turn, as a single list. No elf
+    /// spans two inputs, and errors tell the input they were found in
+    pub fn merge(inputs: impl IntoIterator<Item = (String, R)>) -> Self {
+        let inputs = inputs
+            .into_iter()
+            .map(|(name, reader)| Input {
+                name: Some(name),
+                reader,
+            })
+            .collect();
+        Self::from_inputs(inputs)
+    }
+
+    /// Reads the elves of `reader` as the rest of an input, after `line`
+    /// lines and `index` elves named in `names`
+    pub(crate) fn resume(reader: R, line: usize, index: usize, names: Names) -> Self {
+        Self {
+            line,
+            index,
+            names,
+            ..Self::new(reader)
+        }
+    }
+
+    fn from_inputs(inputs: Vec<Input<R>>) -> Self {
+        Self {
+            inputs: inputs.into(),
+            buffer: String::new(),
+            line: 0,
+            lines: 0,
+            index: 0,
+            names: HashMap::new(),
+            failed: false,
+        }
+    }
+
+    /// Number of lines read so far, in every input
+    pub fn lines(&self) -> usize {
+        self.lines + self.line
+    }
+
+    /// Names of the elves read so far
+    pub(crate) fn into_names(self) -> Names {
+        self.names
+    }
+
+    /// Reads the next elf, `None` at the end of the last input
+    fn read_elf(&mut self) -> Result<Option<Elf>, ReadError> {
+        let mut items = Vec::new();
+        let mut total: u64 = 0;
+        let mut header: Option<Header> = None;
+        // Whether the group has a line yet, so a `#` line is a comment
+        let mut started = false;
+
+        while let Some(input) = self.inputs.front_mut() {
+            self.buffer.clear();
+            if input.reader.read_line(&mut self.buffer)? == 0 {
+                if !items.is_empty() {
+                    break;
+                }
+                // A header at the end of an input names nobody
+                self.inputs.pop_front();
+                self.lines += self.line;
+                self.line = 0;
+                (header, started) = (None, false);
+                continue;
+            }
+            self.line += 1;
+
+            let text = self.buffer.trim_end_matches(['\n', '\r']);
+            let calories = text.trim();
+            if calories.is_empty() {
+                if items.is_empty() {
+                    (header, started) = (None, false);
+                    continue;
+                }
+                break;
+            }
+
+            if let Some(comment) = calories.strip_prefix('#') {
+                let name = comment.trim();
+                if !started && !name.is_empty() {
+                    header = Some(Header {
+                        name: name.to_string(),
+                        line: self.line,
+                        text: text.to_string(),
+                    });
+                }
+                started = true;
+                continue;
+            }
+            started = true;
+
+            // Checked at the first item, as headers of comment groups are
+            // no names
+            if items.is_empty() {
+                if let Some(header) = &header {
+                    let input = self.inputs.front().and_then(|input| input.name.clone());
+                    record_name(&mut self.names, header, input)?;
+                }
+            }
+
+            let overflow = || ReadError::Overflow {
+                elf: self.index,
+                line: self.line,
+                source_line: text.to_string(),
+            };
+            let calories = calories.parse::<u64>().map_err(|e| match e.kind() {
+                IntErrorKind::PosOverflow => overflow(),
+                _ => {
+                    let message = format!("Invalid number \"{calories}\"");
+                    ReadError::Invalid(Line::new(self.line, text).error(message, calories))
+                }
+            })?;
+            total = total.checked_add(calories).ok_or_else(overflow)?;
+            items.push(calories);
+        }
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        let elf = Elf {
+            index: self.index,
+            name: header.map(|header| header.name),
+            items,
+            total,
+        };
+        self.index += 1;
+        Ok(Some(elf))
+    }
+}
+
+/// Records the name of `header`, found in `input`, unless an earlier elf has
+/// it
+fn record_name(names: &mut Names, header: &Header, input: Option<String>) -> Result<(), ReadError> {
+    if let Some((first_line, first_input)) = names.get(&header.name) {
+        return Err(ReadError::Duplicate {
+            name: header.name.clone(),
+            line: header.line,
+            source_line: header.text.clone(),
+            first_line: *first_line,
+            first_input: first_input.clone(),
+        });
+    }
+
+    names.insert(header.name.clone(), (header.line, input));
+    Ok(())
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let elf = self
+            .read_elf()
+            .map_err(|error| match self.inputs.front() {
+                Some(Input {
+                    name: Some(input), ..
+                }) => ReadError::In {
+                    input: input.clone(),
+                    error: Box::new(error),
+                },
+                _ => error,
+            })
+            .transpose();
+        self.failed = matches!(elf, Some(Err(_)));
+        elf
+    }
+}
+
+/// Reads every elf of `reader`, see [`Elves`]
+pub fn read_elves(reader: impl BufRead) -> Result<Vec<Elf>, ReadError> {
+    Elves::new(reader).collect()
+}
+
+/// Reads every elf of each named input in turn, see [`Elves::merge`]
+pub fn merge_elves<R: BufRead>(
+    inputs: impl IntoIterator<Item = (String, R)>,
+) -> Result<Vec<Elf>, ReadError> {
+    Elves::merge(inputs).collect()
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Memory the subset search may use by default, in bytes
+pub const DEFAULT_MEMORY: usize = 256 * 1024 * 1024;
+
+/// Marks sums no subset adds up to
+const UNREACHED: u32 = u32::MAX;
+
+/// Marks the empty sum, reached without any value
+const EMPTY: u32 = u32::MAX - 1;
+
+/// Values whose sum is the closest to a target without going over it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Subset {
+    /// Positions of the values, in increasing order
+    pub indices: Vec<usize>,
+    /// Sum of the values
+    pub sum: u64,
+    /// Whether the sum is the target itself
+    pub exact: bool,
+}
+
+/// The subset search would need more memory than allowed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubsetError {
+    /// Memory the search needs, in bytes
+    pub needed: usize,
+    /// Memory the search may use, in bytes
+    pub limit: usize,
+}
+
+impl std::error::Error for SubsetError {}
+
+impl fmt::Display for SubsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Target is too large: the search needs {} bytes, more than the limit of {}",
+            self.needed, self.limit
+        )
+    }
+}
+
+/// Finds the values summing to `target`, or else to the largest sum below it,
+/// using at most `memory` bytes.
+///
+/// Runs a dynamic programming search over every sum up to the target, taking
+/// 4 bytes per sum. Values are first divided by their greatest common divisor
+/// and sums are bounded by the total of the values, so most searches need far
+/// fewer sums than the target
+pub fn closest_subset(values: &[u64], target: u64, memory: usize) -> Result<Subset, SubsetError> {
+    // Values over the target or of 0 calories never help
+    let candidates: Vec<(usize, u64)> = values
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|&(_, value)| value > 0 && value <= target)
+        .collect();
+
+    let divisor = candidates
+        .iter()
+        .fold(0, |divisor, &(_, value)| gcd(divisor, value))
+        .max(1);
+    let total: u128 = candidates
+        .iter()
+        .map(|&(_, value)| u128::from(value / divisor))
+        .sum();
+    let sums = u128::from(target / divisor).min(total) + 1;
+
+    let needed = sums.saturating_mul(4);
+    if needed > memory as u128 || candidates.len() >= EMPTY as usize {
+        return Err(SubsetError {
+            needed: usize::try_from(needed).unwrap_or(usize::MAX),
+            limit: memory,
+        });
+    }
+    let sums = sums as usize;
+
+    // Item that first reached each sum, so the subset can be rebuilt
+    let mut first = vec![UNREACHED; sums];
+    first[0] = EMPTY;
+    for (candidate, &(_, value)) in candidates.iter().enumerate() {
+        let value = (value / divisor) as usize;
+        for sum in (value..sums).rev() {
+            if first[sum] == UNREACHED && first[sum - value] != UNREACHED {
+                first[sum] = candidate as u32;
+            }
+        }
+    }
+
+    let best = (0..sums)
+        .rev()
+        .find(|&sum| first[sum] != UNREACHED)
+        .unwrap_or(0);
+
+    // Each sum was first reached from a smaller one that only used earlier
+    // values, so following `first` back never uses a value twice
+    let mut indices = Vec::new();
+    let mut sum = best;
+    while first[sum] != EMPTY {
+        let (index, value) = candidates[first[sum] as usize];
+        indices.push(index);
+        sum -= (value / divisor) as usize;
+    }
+    indices.reverse();
+
+    let sum = best as u64 * divisor;
+    Ok(Subset {
+        indices,
+        sum,
+        exact: sum == target,
+    })
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
@@ -0,0 +1,228 @@
+use std::io::{self, BufRead};
+
+use rayon::prelude::*;
+
+use crate::{stream::Names, Carrier, Elves, Histogram, Rank, Ranking, ReadError, Stats};
+
+/// Everything the binary reports about the elves, gathered in one pass over
+/// the input without keeping their items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    /// Calories carried by each elf, in input order
+    pub totals: Vec<u64>,
+    /// Name of each elf, in input order
+    pub names: Vec<Option<String>>,
+    /// Number of items carried by all the elves
+    pub items: usize,
+    /// Number of lines read
+    pub lines: usize,
+    /// Elves carrying the most calories, most first
+    pub top: Vec<Carrier>,
+    /// Elves carrying the fewest calories, fewest first
+    pub bottom: Vec<Carrier>,
+}
+
+impl Summary {
+    /// Reads the elves of `reader`, ranking `top` and `bottom` elves at each
+    /// end
+    pub fn read(reader: impl BufRead, top: usize, bottom: usize) -> Result<Self, ReadError> {
+        Self::of_elves(&mut Elves::new(reader), top, bottom)
+    }
+
+    /// [`Summary::read`] on each named input in turn, see [`Elves::merge`]
+    pub fn merge<R: BufRead>(
+        inputs: impl IntoIterator<Item = (String, R)>,
+        top: usize,
+        bottom: usize,
+    ) -> Result<Self, ReadError> {
+        Self::of_elves(&mut Elves::merge(inputs), top, bottom)
+    }
+
+    fn of_elves<R: BufRead>(
+        elves: &mut Elves<R>,
+        top: usize,
+        bottom: usize,
+    ) -> Result<Self, ReadError> {
+        let mut totals = Vec::new();
+        let mut names = Vec::new();
+        let mut items = 0;
+        let mut top = Ranking::new(Rank::Top, top);
+        let mut bottom = Ranking::new(Rank::Bottom, bottom);
+
+        for elf in elves.by_ref() {
+            let elf = elf?;
+            totals.push(elf.total);
+            items += elf.items.len();
+            top.offer(Carrier::from(&elf));
+            bottom.offer(Carrier::from(&elf));
+            names.push(elf.name);
+        }
+
+        Ok(Self {
+            totals,
+            names,
+            items,
+            lines: elves.lines(),
+            top: top.into_carriers(),
+            bottom: bottom.into_carriers(),
+        })
+    }
+
+    /// [`Summary::read`] on the current rayon thread pool. Reads `reader` in
+    /// batches of about `batch` bytes ending at blank lines, and splits each
+    /// batch into about `chunks` chunks read in parallel, so only one batch is
+    /// held in memory. Gives the same summary, and the same first error, as
+    /// reading the input in order: when a chunk fails or names an elf of an
+    /// earlier chunk, its batch is read again in order to find the first error
+    pub fn read_parallel(
+        mut reader: impl BufRead,
+        top: usize,
+        bottom: usize,
+        chunks: usize,
+        batch: usize,
+    ) -> Result<Self, ReadError> {
+        let mut merged = Self {
+            totals: Vec::new(),
+            names: Vec::new(),
+            items: 0,
+            lines: 0,
+            top: Vec::new(),
+            bottom: Vec::new(),
+        };
+        let mut ranked_top = Ranking::new(Rank::Top, top);
+        let mut ranked_bottom = Ranking::new(Rank::Bottom, bottom);
+        let mut names = Names::new();
+        let mut buffer = Vec::new();
+
+        while read_batch(&mut reader, batch, &mut buffer)? > 0 {
+            let summaries: Vec<Result<(Self, Names), ReadError>> = split(&buffer, chunks)
+                .into_par_iter()
+                .map(|chunk| {
+                    let mut elves = Elves::new(chunk);
+                    let summary = Self::of_elves(&mut elves, top, bottom)?;
+                    Ok((summary, elves.into_names()))
+                })
+                .collect();
+
+            let Some(found) = batch_names(&summaries, &names, merged.lines) else {
+                // The first error may come before the failing line of the
+                // chunk, or be a name given in an earlier chunk
+                let mut elves =
+                    Elves::resume(&buffer[..], merged.lines, merged.totals.len(), names);
+                let error = elves.find_map(Result::err);
+                return Err(error.expect("Reading in order fails where a chunk did"));
+            };
+            names.extend(found);
+
+            for (summary, _) in summaries.into_iter().flatten() {
+                // Indices of a chunk start after those of the previous
+                let elves = merged.totals.len();
+                for (ranking, carriers) in [
+                    (&mut ranked_top, summary.top),
+                    (&mut ranked_bottom, summary.bottom),
+                ] {
+                    for mut carrier in carriers {
+                        carrier.index += elves;
+                        ranking.offer(carrier);
+                    }
+                }
+                merged.totals.extend(summary.totals);
+                merged.names.extend(summary.names);
+                merged.items += summary.items;
+                merged.lines += summary.lines;
+            }
+        }
+
+        merged.top = ranked_top.into_carriers();
+        merged.bottom = ranked_bottom.into_carriers();
+        Ok(merged)
+    }
+
+    /// Statistics of the calories carried, `None` when there are no elves
+    pub fn stats(&self) -> Option<Stats> {
+        Stats::of_totals(self.totals.clone(), self.items)
+    }
+
+    /// Histogram of the calories carried in at most `buckets` buckets
+    pub fn histogram(&self, buckets: usize) -> Histogram {
+        Histogram::of_totals(&self.totals, buckets)
+    }
+}
+
+/// Names of the elves of a batch read in `summaries`, with lines counted from
+/// the start of the input, `lines` lines before the batch. `None` when a
+/// chunk failed or a name is in `names` or in two chunks
+fn batch_names(
+    summaries: &[Result<(Summary, Names), ReadError>],
+    names: &Names,
+    mut lines: usize,
+) -> Option<Names> {
+    let mut found = Names::new();
+    for summary in summaries {
+        let (summary, chunk_names) = summary.as_ref().ok()?;
+        for (name, (line, input)) in chunk_names {
+            if names.contains_key(name) || found.contains_key(name) {
+                return None;
+            }
+            found.insert(name.clone(), (lines + line, input.clone()));
+        }
+        lines += summary.lines;
+    }
+    Some(found)
+}
+
+/// Reads lines of `reader` into `buffer` until it holds at least `size` bytes
+/// and ends with a blank line, or the input ends. Returns the bytes read
+fn read_batch(reader: &mut impl BufRead, size: usize, buffer: &mut Vec<u8>) -> io::Result<usize> {
+    buffer.clear();
+    loop {
+        let start = buffer.len();
+        if reader.read_until(b'\n', buffer)? == 0 {
+            break;
+        }
+        if buffer.len() >= size && buffer[start..].iter().all(u8::is_ascii_whitespace) {
+            break;
+        }
+    }
+    Ok(buffer.len())
+}
+
+/// Splits `input` into about `count` chunks of similar size, each ending
+/// after a blank line so no elf is split
+fn split(input: &[u8], count: usize) -> Vec<&[u8]> {
+    let size = input.len().div_ceil(count.max(1)).max(1);
+    let mut chunks = Vec::with_capacity(count);
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let end = blank_line_end(rest, size).unwrap_or(rest.len());
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    chunks
+}
+
+/// End of the first blank line starting at or after `from`, past its newline
+fn blank_line_end(input: &[u8], from: usize) -> Option<usize> {
+    // Lines are only checked from their start
+    let mut start = if from == 0 {
+        0
+    } else {
+        from + input.get(from - 1..)?.iter().position(|&b| b == b'\n')?
+    };
+
+    while start < input.len() {
+        let end = input[start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(input.len(), |newline| start + newline + 1);
+        if input[start..end].iter().all(u8::is_ascii_whitespace) {
+            return Some(end);
+        }
+        start = end;
+    }
+
+    None
+}
@@ -0,0 +1,42 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{rank, sum_calories, CalorieCounting, Elf, Rank};
+
+/// Step of looking for the elves carrying the most calories
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// The elf at `index`, counted from 0, is compared with the ranked elves
+    Elf {
+        /// Position of the elf in the input
+        index: usize,
+        /// Calories the elf carries
+        calories: u64,
+    },
+    /// The elf at `index` joined the ranked elves
+    Ranked {
+        /// Position of the elf in the input
+        index: usize,
+        /// Calories the elf carries
+        calories: u64,
+        /// Position of the elf it pushed out, if the ranking was full
+        replaced: Option<usize>,
+    },
+}
+
+impl Trace for CalorieCounting {
+    type Event = Event;
+
+    fn solve_traced(elves: &Vec<Elf>, part: u8, tracer: &mut impl Tracer<Event>) -> Result<String> {
+        let count = match part {
+            1 => 1,
+            2 => 3,
+            _ => return Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        };
+
+        let top = rank(elves, Rank::Top, count, tracer);
+        Ok(sum_calories(&top).to_string())
+    }
+}
@@ -0,0 +1,424 @@
+use aoc_common::{trace::Trace, Solution};
+use day_1_calorie_counting::{
+    bottom_carriers, closest_subset, merge_elves, read_elves, rebalance, top_carriers,
+    CalorieCounting, Carrier, Elf, Elves, Event, Histogram, Move, Offer, Rank, Ranking, ReadError,
+    Stats, Subset, SubsetError, Summary, DEFAULT_MEMORY,
+};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+/// Elves carrying a single item each
+fn elves(totals: &[u64]) -> Vec<Elf> {
+    totals
+        .iter()
+        .enumerate()
+        .map(|(index, &total)| Elf::new(index, vec![total]))
+        .collect()
+}
+
+#[test]
+fn parses_elf_inventories() {
+    let elves = CalorieCounting::parse(EXAMPLE).unwrap();
+    let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    assert_eq!(totals, [6000, 4000, 11000, 24000, 10000]);
+
+    assert_eq!(elves[1].items, [4000]);
+    assert_eq!(elves[3], Elf::new(3, vec![7000, 8000, 9000]));
+}
+
+#[test]
+fn solves_example() {
+    let totals = CalorieCounting::parse(EXAMPLE).unwrap();
+    assert_eq!(CalorieCounting::part1(&totals).unwrap(), 24000);
+    assert_eq!(CalorieCounting::part2(&totals).unwrap(), 45000);
+}
+
+#[test]
+fn finds_top_carriers() {
+    let totals = CalorieCounting::parse(EXAMPLE).unwrap();
+
+    let top = top_carriers(&totals, 3);
+    let ranked: Vec<(usize, u64)> = top.iter().map(|c| (c.index, c.calories)).collect();
+    assert_eq!(ranked, [(3, 24000), (2, 11000), (4, 10000)]);
+    assert_eq!(top[0].to_string(), "Elf 4");
+
+    assert_eq!(top_carriers(&totals, 10).len(), 5);
+    assert!(top_carriers(&totals, 0).is_empty());
+}
+
+#[test]
+fn finds_bottom_carriers() {
+    let totals = CalorieCounting::parse(EXAMPLE).unwrap();
+
+    let bottom: Vec<usize> = bottom_carriers(&totals, 2)
+        .iter()
+        .map(|c| c.index)
+        .collect();
+    assert_eq!(bottom, [1, 0]);
+}
+
+#[test]
+fn breaks_ties_by_input_order() {
+    let totals = elves(&[5, 9, 5, 9, 5]);
+
+    let top: Vec<usize> = top_carriers(&totals, 3).iter().map(|c| c.index).collect();
+    assert_eq!(top, [1, 3, 0]);
+
+    let bottom: Vec<usize> = bottom_carriers(&totals, 2)
+        .iter()
+        .map(|c| c.index)
+        .collect();
+    assert_eq!(bottom, [0, 2]);
+}
+
+#[test]
+fn ranking_reports_who_was_pushed_out() {
+    let carrier = |index, calories| Carrier {
+        index,
+        name: None,
+        calories,
+    };
+    let mut ranking = Ranking::new(Rank::Top, 1);
+
+    assert_eq!(ranking.offer(carrier(0, 10)), Offer::Joined(None));
+    assert_eq!(ranking.offer(carrier(1, 10)), Offer::Rejected);
+    assert_eq!(
+        ranking.offer(carrier(2, 20)),
+        Offer::Joined(Some(carrier(0, 10)))
+    );
+    assert_eq!(ranking.into_carriers(), [carrier(2, 20)]);
+}
+
+#[test]
+fn summarizes_calories() {
+    let elves = CalorieCounting::parse(EXAMPLE).unwrap();
+    let stats = Stats::of(&elves).unwrap();
+
+    assert_eq!((stats.elves, stats.items), (5, 10));
+    assert_eq!((stats.min, stats.max), (4000, 24000));
+    assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+    assert!((stats.std_dev - 6985.7).abs() < 0.1);
+
+    let percentiles: Vec<(u8, f64)> = stats
+        .percentiles
+        .iter()
+        .map(|p| (p.rank, p.calories))
+        .collect();
+    assert_eq!(
+        percentiles,
+        [(25, 6000.0), (75, 11000.0), (90, 18800.0), (99, 23480.0)]
+    );
+
+    assert_eq!(Stats::of(&[]), None);
+}
+
+#[test]
+fn counts_elves_in_buckets() {
+    // 10 calories in 3 buckets, 4 calories wide
+    let histogram = Histogram::of(&elves(&[1, 2, 2, 7, 10]), 3);
+    let buckets: Vec<(u64, u64, usize)> = histogram
+        .buckets
+        .iter()
+        .map(|bucket| (bucket.from, bucket.to, bucket.count))
+        .collect();
+    assert_eq!(buckets, [(1, 4, 3), (5, 8, 1), (9, 10, 1)]);
+    assert_eq!(
+        Histogram::of(&elves(&[5, 5]), 4).to_string(),
+        "5-5 | ######################################## 2\n"
+    );
+    assert!(Histogram::of(&[], 4).buckets.is_empty());
+}
+
+#[test]
+fn reports_invalid_calories() {
+    let error = CalorieCounting::parse("100\n\n2x00\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.len), (3, 1, 4));
+}
+
+#[test]
+fn streams_elves_one_at_a_time() {
+    let mut elves = Elves::new("1\r\n2\r\n\r\n\r\n\n3\n".as_bytes());
+
+    assert_eq!(elves.next().unwrap().unwrap(), Elf::new(0, vec![1, 2]));
+    assert_eq!(elves.next().unwrap().unwrap(), Elf::new(1, vec![3]));
+    assert!(elves.next().is_none());
+}
+
+#[test]
+fn reports_overflow_with_elf_and_line() {
+    let input = format!("1\n\n{}\n1\n\n2\n", u64::MAX);
+    let mut elves = Elves::new(input.as_bytes());
+
+    assert!(elves.next().unwrap().is_ok());
+    match elves.next().unwrap() {
+        Err(ReadError::Overflow { elf, line, .. }) => assert_eq!((elf, line), (1, 4)),
+        other => panic!("Expected an overflow, got {other:?}"),
+    }
+    // Reading stops at the first error
+    assert!(elves.next().is_none());
+
+    let error = CalorieCounting::parse(&input).unwrap_err();
+    assert_eq!(error.message, "Calories carried by elf 2 overflow");
+    assert_eq!(error.line, 4);
+}
+
+#[test]
+fn reports_read_errors() {
+    let error = read_elves(&b"1\n\xff\n"[..]).unwrap_err();
+    assert!(matches!(error, ReadError::Io(_)));
+    assert!(error.diagnostic().is_none());
+}
+
+#[test]
+fn reads_named_elves_and_comments() {
+    let input =
+        "# Inventory of the north team\n\n# Alice\n# likes snacks\n1000\n2000\n\n3000\n# Bob\n";
+    let elves = read_elves(input.as_bytes()).unwrap();
+    assert_eq!(
+        elves,
+        [
+            Elf::new(0, vec![1000, 2000]).with_name("Alice"),
+            Elf::new(1, vec![3000]),
+        ]
+    );
+    assert_eq!(top_carriers(&elves, 1)[0].to_string(), "Alice");
+
+    let error = CalorieCounting::parse("# Alice\n1\n\n# Bob\n2\n\n# Alice\n3\n").unwrap_err();
+    assert_eq!(error.message, "Elf \"Alice\" is already named on line 1");
+    assert_eq!((error.line, error.column, error.len), (7, 3, 5));
+}
+
+#[test]
+fn merges_inputs_keeping_names() {
+    let inputs = [
+        (
+            "north.txt".to_string(),
+            "# Alice\n1000\n\n2000\n".as_bytes(),
+        ),
+        ("south.txt".to_string(), "# Bob\n3000\n".as_bytes()),
+    ];
+    let elves = merge_elves(inputs).unwrap();
+    let named: Vec<(usize, Option<&str>, u64)> = elves
+        .iter()
+        .map(|elf| (elf.index, elf.name.as_deref(), elf.total))
+        .collect();
+    assert_eq!(
+        named,
+        [
+            (0, Some("Alice"), 1000),
+            (1, None, 2000),
+            (2, Some("Bob"), 3000)
+        ]
+    );
+
+    // Errors tell their input, and lines count from its start
+    let inputs = [
+        ("north.txt".to_string(), "# Alice\n1000\n".as_bytes()),
+        (
+            "south.txt".to_string(),
+            "2000\n\n# Alice\n3000\n".as_bytes(),
+        ),
+    ];
+    let error = merge_elves(inputs).unwrap_err();
+    assert!(matches!(&error, ReadError::In { input, .. } if input == "south.txt"));
+    let diagnostic = error.diagnostic().unwrap();
+    assert_eq!(
+        diagnostic.message,
+        "Elf \"Alice\" is already named on line 1 of north.txt"
+    );
+    assert_eq!(diagnostic.line, 3);
+    assert!(error.to_string().starts_with("In south.txt: Elf \"Alice\""));
+
+    let summary = Summary::merge(
+        [
+            ("a".to_string(), "1\n".as_bytes()),
+            ("b".to_string(), "2\n".as_bytes()),
+        ],
+        1,
+        0,
+    )
+    .unwrap();
+    assert_eq!((summary.totals, summary.lines), (vec![1, 2], 2));
+}
+
+#[test]
+fn summarizes_in_parallel_chunks() {
+    let summary = Summary::read(EXAMPLE.as_bytes(), 2, 1).unwrap();
+    assert_eq!(summary.totals, [6000, 4000, 11000, 24000, 10000]);
+    assert_eq!((summary.items, summary.lines), (10, 14));
+    assert_eq!(
+        summary.top,
+        top_carriers(&CalorieCounting::parse(EXAMPLE).unwrap(), 2)
+    );
+
+    // More chunks than elves, and batches of a single elf
+    for chunks in [1, 2, 5, 100] {
+        for batch in [1, 20, 1 << 20] {
+            let parallel = Summary::read_parallel(EXAMPLE.as_bytes(), 2, 1, chunks, batch).unwrap();
+            assert_eq!(parallel, summary);
+        }
+    }
+}
+
+#[test]
+fn parallel_errors_point_at_the_whole_input() {
+    let input = format!("1\n\n2\n\n3\n\n{}\n1\n", u64::MAX);
+    for batch in [1, 4, 1 << 20] {
+        match Summary::read_parallel(input.as_bytes(), 1, 0, 4, batch) {
+            Err(ReadError::Overflow { elf, line, .. }) => assert_eq!((elf, line), (3, 8)),
+            other => panic!("Expected an overflow, got {other:?}"),
+        }
+    }
+
+    // Named in an earlier batch
+    let input = "# Alice\n1\n\n2\n\n# Alice\n3\n";
+    let error = Summary::read_parallel(input.as_bytes(), 1, 0, 2, 1).unwrap_err();
+    let diagnostic = error.diagnostic().unwrap();
+    assert_eq!(diagnostic.line, 6);
+    assert_eq!(
+        diagnostic.message,
+        "Elf \"Alice\" is already named on line 1"
+    );
+}
+
+#[test]
+fn rebalances_items_between_elves() {
+    let elves = CalorieCounting::parse(EXAMPLE).unwrap();
+    let plan = rebalance(&elves);
+    assert_eq!(
+        (plan.before, plan.after, plan.optimal),
+        (24000, 11000, true)
+    );
+
+    let mut totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    for step in &plan.moves {
+        assert_eq!(elves[step.from].items[step.item], step.calories);
+        totals[step.from] -= step.calories;
+        totals[step.to] += step.calories;
+    }
+    assert_eq!(totals, [11000; 5]);
+}
+
+#[test]
+fn rebalancing_prefers_few_moves() {
+    // Moving the 4 alone is enough for every elf to carry 5
+    let elves = vec![
+        Elf::new(0, vec![3, 4, 2]),
+        Elf::new(1, vec![5]),
+        Elf::new(2, vec![1]),
+    ];
+    let plan = rebalance(&elves);
+    assert_eq!((plan.before, plan.after), (9, 5));
+    assert_eq!(
+        plan.moves,
+        [Move {
+            from: 0,
+            from_name: None,
+            item: 1,
+            calories: 4,
+            to: 2,
+            to_name: None,
+        }]
+    );
+
+    // Named elves are named in the moves
+    let named: Vec<Elf> = elves
+        .into_iter()
+        .zip(["Alice", "Bob", "Carol"])
+        .map(|(elf, name)| elf.with_name(name))
+        .collect();
+    let plan = rebalance(&named);
+    assert_eq!(
+        plan.moves[0].to_string(),
+        "Item 2 of Alice (4 calories) to Carol"
+    );
+
+    // Nothing to gain
+    let balanced = vec![Elf::new(0, vec![5]), Elf::new(1, vec![2, 3])];
+    let plan = rebalance(&balanced);
+    assert!(plan.optimal && plan.moves.is_empty());
+    assert_eq!(plan.after, 5);
+}
+
+#[test]
+fn rebalancing_twenty_items_is_exact() {
+    // The best plan is above the even share, so the search has to rule out
+    // every better one
+    let elves: Vec<Elf> = [
+        vec![975, 377, 168, 943, 417],
+        vec![381, 599, 545, 606, 606],
+        vec![716, 634, 690, 408, 887],
+        vec![695, 208, 725],
+        vec![613, 979],
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, items)| Elf::new(index, items))
+    .collect();
+    let plan = rebalance(&elves);
+    assert!(plan.optimal);
+    // An even share would be 2435
+    assert_eq!(plan.after, 2436);
+}
+
+#[test]
+fn finds_elves_summing_to_a_target() {
+    let summary = Summary::read(EXAMPLE.as_bytes(), 1, 0).unwrap();
+
+    let subset = closest_subset(&summary.totals, 21000, DEFAULT_MEMORY).unwrap();
+    assert_eq!(
+        subset,
+        Subset {
+            indices: vec![0, 1, 2],
+            sum: 21000,
+            exact: true
+        }
+    );
+
+    // Nothing sums to 9000, the closest below is a single elf
+    let subset = closest_subset(&summary.totals, 9000, DEFAULT_MEMORY).unwrap();
+    assert_eq!(
+        (subset.indices, subset.sum, subset.exact),
+        (vec![0], 6000, false)
+    );
+
+    let elves = CalorieCounting::parse(EXAMPLE).unwrap();
+    let subset = closest_subset(&elves[3].items, 16000, DEFAULT_MEMORY).unwrap();
+    assert_eq!((subset.indices, subset.exact), (vec![0, 2], true));
+}
+
+#[test]
+fn subset_search_respects_the_memory_bound() {
+    // Sums are counted in thousands, as every total is a multiple of 1000
+    assert!(closest_subset(&[6000, 4000, 11000], 20000, 100).is_ok());
+    assert_eq!(
+        closest_subset(&[6001, 4000], 20000, 1000),
+        Err(SubsetError {
+            needed: 40_008,
+            limit: 1000
+        })
+    );
+    assert_eq!(closest_subset(&[], 5, 4).unwrap().sum, 0);
+}
+
+#[test]
+fn traces_top_carriers() {
+    let input = CalorieCounting::parse(EXAMPLE).unwrap();
+    let mut steps = Vec::new();
+    let answer = CalorieCounting::solve_traced(&input, 1, &mut steps).unwrap();
+    assert_eq!(answer, "24000");
+
+    let ranked: Vec<&Event> = steps
+        .iter()
+        .filter(|step| matches!(step, Event::Ranked { .. }))
+        .collect();
+    assert_eq!(ranked.len(), 3);
+    assert_eq!(
+        ranked[2],
+        &Event::Ranked {
+            index: 3,
+            calories: 24000,
+            replaced: Some(2)
+        }
+    );
+}
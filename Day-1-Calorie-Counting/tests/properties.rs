@@ -0,0 +1,336 @@
+use std::cmp::Reverse;
+
+use aoc_common::Solution;
+use day_1_calorie_counting::{
+    bottom_carriers, closest_subset, read_elves, rebalance, top_carriers, CalorieCounting, Elf,
+    Histogram, Plan, ReadError, Stats, Summary, DEFAULT_MEMORY, EXACT_ITEMS,
+};
+use proptest::prelude::*;
+
+fn inventories() -> impl Strategy<Value = Vec<Vec<u64>>> {
+    prop::collection::vec(prop::collection::vec(0..100_000u64, 1..10), 1..50)
+}
+
+fn render(inventories: &[Vec<u64>]) -> String {
+    let elves: Vec<String> = inventories
+        .iter()
+        .map(|items| {
+            let items: Vec<String> = items.iter().map(u64::to_string).collect();
+            items.join("\n")
+        })
+        .collect();
+    elves.join("\n\n")
+}
+
+/// Totals of `elves` after the moves of `plan`, checking that each move takes
+/// an item its elf carries, at most once
+fn apply(elves: &[Elf], plan: &Plan) -> Vec<u64> {
+    let mut totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    let mut moved = std::collections::HashSet::new();
+    for step in &plan.moves {
+        assert_eq!(elves[step.from].items[step.item], step.calories);
+        assert!(moved.insert((step.from, step.item)));
+        assert_ne!(step.from, step.to);
+        totals[step.from] -= step.calories;
+        totals[step.to] += step.calories;
+    }
+    totals
+}
+
+/// Least most calories carried over every way to hand out the items
+fn brute_force(elves: &[Elf]) -> u64 {
+    fn best(items: &[u64], loads: &mut Vec<u64>) -> u64 {
+        let Some((&item, rest)) = items.split_first() else {
+            return loads.iter().copied().max().unwrap_or(0);
+        };
+        (0..loads.len())
+            .map(|elf| {
+                loads[elf] += item;
+                let max = best(rest, loads);
+                loads[elf] -= item;
+                max
+            })
+            .min()
+            .unwrap()
+    }
+
+    let items: Vec<u64> = elves.iter().flat_map(|elf| elf.items.clone()).collect();
+    best(&items, &mut vec![0; elves.len()])
+}
+
+/// Elves carrying a single item each
+fn elves(totals: &[u64]) -> Vec<Elf> {
+    totals
+        .iter()
+        .enumerate()
+        .map(|(index, &total)| Elf::new(index, vec![total]))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn parse_sums_each_elf(inventories in inventories()) {
+        let elves = CalorieCounting::parse(&render(&inventories)).unwrap();
+        let items: Vec<Vec<u64>> = elves.iter().map(|elf| elf.items.clone()).collect();
+        let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+        let expected: Vec<u64> = inventories.iter().map(|items| items.iter().sum()).collect();
+        prop_assert_eq!(items, inventories);
+        prop_assert_eq!(totals, expected);
+    }
+
+    #[test]
+    fn top_carriers_are_the_largest_totals(
+        totals in prop::collection::vec(0..1_000u64, 0..50),
+        count in 0..10usize,
+    ) {
+        let top = top_carriers(&elves(&totals), count);
+
+        // A stable sort keeps equal totals in input order, like the ranking
+        let mut sorted: Vec<(usize, u64)> = totals.iter().copied().enumerate().collect();
+        sorted.sort_by_key(|&(_, calories)| Reverse(calories));
+        sorted.truncate(count);
+
+        let ranked: Vec<(usize, u64)> = top.iter().map(|c| (c.index, c.calories)).collect();
+        prop_assert_eq!(ranked, sorted);
+    }
+
+    #[test]
+    fn bottom_carriers_are_the_smallest_totals(
+        totals in prop::collection::vec(0..1_000u64, 0..50),
+        count in 0..10usize,
+    ) {
+        let bottom = bottom_carriers(&elves(&totals), count);
+
+        let mut sorted: Vec<(usize, u64)> = totals.iter().copied().enumerate().collect();
+        sorted.sort_by_key(|&(_, calories)| calories);
+        sorted.truncate(count);
+
+        let ranked: Vec<(usize, u64)> = bottom.iter().map(|c| (c.index, c.calories)).collect();
+        prop_assert_eq!(ranked, sorted);
+    }
+
+    #[test]
+    fn part1_is_the_largest_total(inventories in inventories()) {
+        let elves = CalorieCounting::parse(&render(&inventories)).unwrap();
+        prop_assert_eq!(
+            CalorieCounting::part1(&elves).unwrap(),
+            elves.iter().map(|elf| elf.total).max().unwrap()
+        );
+        prop_assert!(
+            CalorieCounting::part2(&elves).unwrap() >= u128::from(CalorieCounting::part1(&elves).unwrap())
+        );
+    }
+
+    #[test]
+    fn stats_stay_within_the_totals(
+        totals in prop::collection::vec(any::<u64>(), 1..50),
+        buckets in 1..20usize,
+    ) {
+        let elves = elves(&totals);
+        let stats = Stats::of(&elves).unwrap();
+        let (min, max) = (stats.min as f64, stats.max as f64);
+
+        prop_assert!(min <= stats.mean && stats.mean <= max);
+        prop_assert!(min <= stats.median && stats.median <= max);
+        prop_assert!(stats.percentiles.windows(2).all(|p| p[0].calories <= p[1].calories));
+
+        let histogram = Histogram::of(&elves, buckets);
+        prop_assert!(histogram.buckets.len() <= buckets);
+        prop_assert_eq!(histogram.buckets.iter().map(|bucket| bucket.count).sum::<usize>(), totals.len());
+    }
+
+    #[test]
+    fn streams_any_line_endings_and_blank_runs(
+        inventories in inventories(),
+        blanks in prop::collection::vec(1..4usize, 50),
+        crlf in any::<bool>(),
+    ) {
+        let newline = if crlf { "\r\n" } else { "\n" };
+        let text: String = inventories
+            .iter()
+            .zip(&blanks)
+            .map(|(items, &blanks)| {
+                let items: Vec<String> = items.iter().map(u64::to_string).collect();
+                items.join(newline) + &newline.repeat(blanks + 1)
+            })
+            .collect();
+
+        let streamed = read_elves(text.as_bytes()).unwrap();
+        prop_assert_eq!(streamed, CalorieCounting::parse(&render(&inventories)).unwrap());
+    }
+
+    #[test]
+    fn overflow_is_reported_instead_of_wrapping(
+        items in prop::collection::vec(any::<u64>(), 1..5),
+    ) {
+        let text: Vec<String> = items.iter().map(u64::to_string).collect();
+        let exact: u128 = items.iter().map(|&calories| u128::from(calories)).sum();
+
+        match read_elves(text.join("\n").as_bytes()) {
+            Ok(elves) => prop_assert_eq!(u128::from(elves[0].total), exact),
+            Err(ReadError::Overflow { elf, .. }) => {
+                prop_assert!(exact > u128::from(u64::MAX));
+                prop_assert_eq!(elf, 0);
+            }
+            Err(e) => prop_assert!(false, "Unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn parallel_summary_matches_sequential(
+        inventories in prop::collection::vec(prop::collection::vec(0..50u64, 1..4), 0..60),
+        blanks in prop::collection::vec(1..3usize, 60),
+        chunks in 1..12usize,
+        batch in 1..64usize,
+        top in 0..5usize,
+        bottom in 0..5usize,
+    ) {
+        let text: String = inventories
+            .iter()
+            .zip(&blanks)
+            .map(|(items, &blanks)| {
+                let items: Vec<String> = items.iter().map(u64::to_string).collect();
+                items.join("\n") + &"\n".repeat(blanks + 1)
+            })
+            .collect();
+
+        let sequential = Summary::read(text.as_bytes(), top, bottom).unwrap();
+        let parallel = Summary::read_parallel(text.as_bytes(), top, bottom, chunks, batch).unwrap();
+        prop_assert_eq!(&parallel, &sequential);
+        prop_assert_eq!(parallel.stats(), sequential.stats());
+    }
+
+    #[test]
+    fn parallel_errors_match_sequential(
+        inventories in inventories(),
+        position in any::<prop::sample::Index>(),
+        chunks in 1..12usize,
+        batch in 1..64usize,
+    ) {
+        let mut lines: Vec<String> = render(&inventories).lines().map(String::from).collect();
+        let numbers: Vec<usize> = (0..lines.len()).filter(|i| !lines[*i].is_empty()).collect();
+        lines[*position.get(&numbers)].push('x');
+        let text = lines.join("\n");
+
+        let sequential = Summary::read(text.as_bytes(), 3, 0).unwrap_err();
+        let parallel = Summary::read_parallel(text.as_bytes(), 3, 0, chunks, batch).unwrap_err();
+        prop_assert_eq!(parallel.diagnostic(), sequential.diagnostic());
+    }
+
+    #[test]
+    fn parallel_summary_matches_sequential_with_names(
+        inventories in prop::collection::vec(
+            (prop::option::of(0..20u8), prop::collection::vec(0..50u64, 1..4)),
+            0..40,
+        ),
+        chunks in 1..12usize,
+        batch in 1..64usize,
+    ) {
+        // Few names, so some are given twice
+        let text: String = inventories
+            .iter()
+            .map(|(name, items)| {
+                let header = name.map_or(String::new(), |name| format!("# Elf {name}\n"));
+                let items: Vec<String> = items.iter().map(u64::to_string).collect();
+                format!("{header}{}\n# comment\n\n", items.join("\n"))
+            })
+            .collect();
+
+        let sequential = Summary::read(text.as_bytes(), 3, 1);
+        let parallel = Summary::read_parallel(text.as_bytes(), 3, 1, chunks, batch);
+        match (parallel, sequential) {
+            (Ok(parallel), Ok(sequential)) => {
+                let names: Vec<Option<String>> = inventories
+                    .iter()
+                    .map(|(name, _)| name.map(|name| format!("Elf {name}")))
+                    .collect();
+                prop_assert_eq!(&sequential.names, &names);
+                prop_assert_eq!(parallel, sequential);
+            }
+            (Err(parallel), Err(sequential)) => {
+                let duplicate = matches!(sequential, ReadError::Duplicate { .. });
+                prop_assert!(duplicate);
+                prop_assert_eq!(parallel.diagnostic(), sequential.diagnostic());
+            }
+            (parallel, sequential) => {
+                prop_assert!(false, "{:?} differs from {:?}", parallel, sequential);
+            }
+        }
+    }
+
+    #[test]
+    fn rebalancing_is_optimal_on_small_inputs(
+        inventories in prop::collection::vec(prop::collection::vec(1..30u64, 1..4), 1..4),
+    ) {
+        let elves: Vec<Elf> = inventories
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| Elf::new(index, items))
+            .collect();
+        let plan = rebalance(&elves);
+
+        prop_assert!(plan.optimal);
+        prop_assert_eq!(plan.after, brute_force(&elves));
+        prop_assert_eq!(apply(&elves, &plan).into_iter().max().unwrap(), plan.after);
+    }
+
+    #[test]
+    fn rebalancing_never_makes_things_worse(
+        inventories in prop::collection::vec(prop::collection::vec(1..1_000u64, 1..8), 1..40),
+    ) {
+        let elves: Vec<Elf> = inventories
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| Elf::new(index, items))
+            .collect();
+        let items: usize = elves.iter().map(|elf| elf.items.len()).sum();
+        let plan = rebalance(&elves);
+
+        prop_assert!(plan.after <= plan.before);
+        prop_assert_eq!(apply(&elves, &plan).into_iter().max().unwrap(), plan.after);
+        prop_assert_eq!(plan.moves.is_empty(), plan.after == plan.before);
+        if items <= EXACT_ITEMS {
+            prop_assert!(plan.optimal);
+        }
+    }
+
+    #[test]
+    fn subset_sum_is_the_best_below_the_target(
+        values in prop::collection::vec(0..60u64, 0..12),
+        target in 0..400u64,
+    ) {
+        let subset = closest_subset(&values, target, DEFAULT_MEMORY).unwrap();
+
+        // Every subset, as a bit mask over the values
+        let best = (0..1u32 << values.len())
+            .map(|mask| {
+                (0..values.len())
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| values[i])
+                    .sum::<u64>()
+            })
+            .filter(|&sum| sum <= target)
+            .max()
+            .unwrap();
+        prop_assert_eq!(subset.sum, best);
+        prop_assert_eq!(subset.exact, best == target);
+
+        prop_assert!(subset.indices.windows(2).all(|pair| pair[0] < pair[1]));
+        let sum: u64 = subset.indices.iter().map(|&i| values[i]).sum();
+        prop_assert_eq!(sum, subset.sum);
+    }
+
+    #[test]
+    fn invalid_number_points_at_its_line(
+        inventories in inventories(),
+        position in any::<prop::sample::Index>(),
+    ) {
+        let mut lines: Vec<String> = render(&inventories).lines().map(String::from).collect();
+        let numbers: Vec<usize> = (0..lines.len()).filter(|i| !lines[*i].is_empty()).collect();
+        let line = *position.get(&numbers);
+        lines[line].push('x');
+
+        let error = CalorieCounting::parse(&lines.join("\n")).unwrap_err();
+        prop_assert_eq!(error.line, line + 1);
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::RockPaperScissors;
+
+impl Generator for RockPaperScissors {
+    /// Generates a strategy guide of `size` rounds
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut guide = String::with_capacity(size * 4);
+        for _ in 0..size.max(1) {
+            guide.push(*rng.pick(&['A', 'B', 'C']));
+            guide.push(' ');
+            guide.push(*rng.pick(&['X', 'Y', 'Z']));
+            guide.push('\n');
+        }
+        guide
+    }
+}
@@ -0,0 +1,280 @@
+//! Day 2: Rock Paper Scissors
+//!
+//! Scores a rock paper scissors tournament from an encrypted strategy guide.
+
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::{
+    parse::{self, Line},
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+mod generate;
+mod trace;
+
+pub use trace::Event;
+
+/// [`Solution`] for day 2
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Vec<GuideEntry>;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        parse::lines(input.trim_end())
+            .map(|line| GuideEntry::parse(line.text).map_err(|e| e.on_line(line.number)))
+            .collect()
+    }
+
+    /// Returns the total score of following the strategy guide, where the
+    /// second column is the shape to play
+    fn part1(guide: &Self::Input) -> Result<u32> {
+        Ok(score(guide, play_response, &mut NoTrace))
+    }
+
+    /// Returns the total score of following the strategy guide, where the
+    /// second column is the expected result of each round
+    fn part2(guide: &Self::Input) -> Result<u32> {
+        Ok(score(guide, play_for_result, &mut NoTrace))
+    }
+
+    /// Number of rounds in the strategy guide
+    fn details(guide: &Self::Input) -> Value {
+        json!({ "rounds": guide.len() })
+    }
+}
+
+/// Shape played when the second column is read as a shape
+fn play_response(entry: &GuideEntry) -> Shape {
+    Shape::from_response(&entry.response)
+}
+
+/// Shape played when the second column is read as the expected result
+fn play_for_result(entry: &GuideEntry) -> Shape {
+    let expected_result = MatchResult::from_response(&entry.response);
+    Shape::from_expected_result(&entry.opponent, &expected_result)
+}
+
+/// Total score of playing the shape `player` picks for every round, sending
+/// each round to `tracer`
+fn score(
+    guide: &[GuideEntry],
+    player: fn(&GuideEntry) -> Shape,
+    tracer: &mut impl Tracer<Event>,
+) -> u32 {
+    let mut total = 0;
+
+    for (index, entry) in guide.iter().enumerate() {
+        let shape = player(entry);
+        let points = Round::new(shape, entry.opponent).points();
+        tracer.step(Event::Round {
+            index,
+            opponent: entry.opponent,
+            player: shape,
+            result: shape.play_against(&entry.opponent),
+            points,
+        });
+        total += points;
+    }
+
+    total
+}
+
+/// A line of the strategy guide
+#[derive(Debug)]
+pub struct GuideEntry {
+    /// Shape the opponent is going to play
+    pub opponent: Shape,
+    /// Suggested response to the opponent
+    pub response: Response,
+}
+
+impl GuideEntry {
+    /// Parses a play string into the opponent's shape and the response
+    pub fn parse(play: &str) -> Result<Self, Diagnostic> {
+        let line = Line::new(1, play);
+        let mut moves = line.tokens();
+
+        let opponent = moves.next_token("opponent character")?;
+        let opponent = Shape::parse(opponent)
+            .ok_or_else(|| line.error(format!("Invalid shape \"{opponent}\""), opponent))?;
+
+        let response = moves.next_token("response character")?;
+        let response = Response::parse(response)
+            .ok_or_else(|| line.error(format!("Invalid response \"{response}\""), response))?;
+        moves.finish()?;
+
+        Ok(Self { opponent, response })
+    }
+}
+
+/// Second column of the strategy guide, read as a shape in part 1 and as the
+/// expected result in part 2
+#[derive(Debug)]
+pub enum Response {
+    /// Rock, or losing the round
+    X,
+    /// Paper, or ending the round in a draw
+    Y,
+    /// Scissors, or winning the round
+    Z,
+}
+
+impl Response {
+    /// Parses the second column of a strategy guide line
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "X" => Some(Self::X),
+            "Y" => Some(Self::Y),
+            "Z" => Some(Self::Z),
+            _ => None,
+        }
+    }
+}
+
+/// A round of the tournament, scored from the player's point of view
+#[derive(Debug)]
+pub struct Round {
+    player: Shape,
+    opponent: Shape,
+}
+
+impl Round {
+    /// Creates a round where `player` plays against `opponent`
+    pub fn new(player: Shape, opponent: Shape) -> Self {
+        Self { player, opponent }
+    }
+
+    /// Points the player gets for the round, the shape points plus the
+    /// points of the result
+    pub fn points(&self) -> u32 {
+        let result = self.player.play_against(&self.opponent);
+        result.points() + self.player.points()
+    }
+}
+
+/// Shape played in a round
+#[derive(PartialEq, Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Shape {
+    /// Worth 1 point
+    Rock,
+    /// Worth 2 points
+    Paper,
+    /// Worth 3 points
+    Scissors,
+}
+
+impl Shape {
+    /// Parses the opponent's shape from the first column of the guide
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "A" => Some(Self::Rock),
+            "B" => Some(Self::Paper),
+            "C" => Some(Self::Scissors),
+            _ => None,
+        }
+    }
+
+    /// Shape the player throws when the second column is read as a shape
+    pub fn from_response(response: &Response) -> Self {
+        match response {
+            Response::X => Self::Rock,
+            Response::Y => Self::Paper,
+            Response::Z => Self::Scissors,
+        }
+    }
+
+    /// Shape that gets the `expected_result` when played against `opponent`
+    pub fn from_expected_result(opponent: &Self, expected_result: &MatchResult) -> Self {
+        match opponent {
+            Self::Rock => match expected_result {
+                MatchResult::Lose => Self::Scissors,
+                MatchResult::Draw => Self::Rock,
+                MatchResult::Win => Self::Paper,
+            },
+            Self::Paper => match expected_result {
+                MatchResult::Lose => Self::Rock,
+                MatchResult::Draw => Self::Paper,
+                MatchResult::Win => Self::Scissors,
+            },
+            Self::Scissors => match expected_result {
+                MatchResult::Lose => Self::Paper,
+                MatchResult::Draw => Self::Scissors,
+                MatchResult::Win => Self::Rock,
+            },
+        }
+    }
+
+    /// Points for playing the shape
+    pub fn points(&self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    /// Result of playing the shape against `other`
+    pub fn play_against(&self, other: &Self) -> MatchResult {
+        match self {
+            Self::Rock => match other {
+                Self::Rock => MatchResult::Draw,
+                Self::Paper => MatchResult::Lose,
+                Self::Scissors => MatchResult::Win,
+            },
+            Self::Paper => match other {
+                Self::Rock => MatchResult::Win,
+                Self::Paper => MatchResult::Draw,
+                Self::Scissors => MatchResult::Lose,
+            },
+            Self::Scissors => match other {
+                Self::Rock => MatchResult::Lose,
+                Self::Paper => MatchResult::Win,
+                Self::Scissors => MatchResult::Draw,
+            },
+        }
+    }
+}
+
+/// Result of a round
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchResult {
+    /// Worth 6 points
+    Win,
+    /// Worth 3 points
+    Draw,
+    /// Worth no points
+    Lose,
+}
+
+impl MatchResult {
+    /// Expected result of the round when the second column is read as one
+    pub fn from_response(response: &Response) -> Self {
+        match response {
+            Response::X => Self::Lose,
+            Response::Y => Self::Draw,
+            Response::Z => Self::Win,
+        }
+    }
+
+    /// Points for the result of a round
+    pub fn points(&self) -> u32 {
+        match self {
+            Self::Lose => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{play_for_result, play_response, score, MatchResult, RockPaperScissors, Shape};
+
+/// Step of scoring the strategy guide
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// A round was played
+    Round {
+        /// Position of the round in the guide, counted from 0
+        index: usize,
+        /// Shape the opponent played
+        opponent: Shape,
+        /// Shape the player picked
+        player: Shape,
+        /// Result for the player
+        result: MatchResult,
+        /// Points the player got
+        points: u32,
+    },
+}
+
+impl Trace for RockPaperScissors {
+    type Event = Event;
+
+    fn solve_traced(
+        guide: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(score(guide, play_response, tracer).to_string()),
+            2 => Ok(score(guide, play_for_result, tracer).to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
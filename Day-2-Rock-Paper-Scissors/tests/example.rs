@@ -0,0 +1,69 @@
+use aoc_common::{trace::Trace, Solution};
+use day_2_rock_paper_scissors::{Event, GuideEntry, MatchResult, RockPaperScissors, Round, Shape};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let guide = RockPaperScissors::parse(EXAMPLE).unwrap();
+    assert_eq!(RockPaperScissors::part1(&guide).unwrap(), 15);
+    assert_eq!(RockPaperScissors::part2(&guide).unwrap(), 12);
+}
+
+#[test]
+fn parses_guide_entry() {
+    let entry = GuideEntry::parse("B X").unwrap();
+    assert_eq!(entry.opponent, Shape::Paper);
+}
+
+#[test]
+fn scores_rounds() {
+    assert_eq!(Round::new(Shape::Paper, Shape::Rock).points(), 8);
+    assert_eq!(Round::new(Shape::Rock, Shape::Paper).points(), 1);
+    assert_eq!(Round::new(Shape::Scissors, Shape::Scissors).points(), 6);
+}
+
+#[test]
+fn picks_shape_for_expected_result() {
+    let shape = Shape::from_expected_result(&Shape::Rock, &MatchResult::Win);
+    assert_eq!(shape, Shape::Paper);
+}
+
+#[test]
+fn reports_invalid_shape() {
+    let error = RockPaperScissors::parse("A Y\nD X\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn reports_missing_response() {
+    let error = RockPaperScissors::parse("A\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 2));
+}
+
+#[test]
+fn reports_extra_tokens() {
+    let error = RockPaperScissors::parse("A Y\nA Y Z\n").unwrap_err();
+    assert_eq!(error.message, "Unexpected \"Z\"");
+    assert_eq!((error.line, error.column), (2, 5));
+}
+
+#[test]
+fn traces_rounds() {
+    let input = RockPaperScissors::parse(EXAMPLE).unwrap();
+    let mut steps = Vec::new();
+    let answer = RockPaperScissors::solve_traced(&input, 2, &mut steps).unwrap();
+    assert_eq!(answer, "12");
+
+    assert_eq!(steps.len(), 3);
+    assert_eq!(
+        steps[0],
+        Event::Round {
+            index: 0,
+            opponent: Shape::Rock,
+            player: Shape::Rock,
+            result: MatchResult::Draw,
+            points: 4
+        }
+    );
+}
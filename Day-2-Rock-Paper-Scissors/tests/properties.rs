@@ -0,0 +1,63 @@
+use aoc_common::Solution;
+use day_2_rock_paper_scissors::{GuideEntry, MatchResult, RockPaperScissors, Round, Shape};
+use proptest::{prelude::*, sample::select};
+
+fn shape() -> impl Strategy<Value = Shape> {
+    select(vec![Shape::Rock, Shape::Paper, Shape::Scissors])
+}
+
+fn result() -> impl Strategy<Value = MatchResult> {
+    select(vec![0, 1, 2]).prop_map(|result| match result {
+        0 => MatchResult::Lose,
+        1 => MatchResult::Draw,
+        _ => MatchResult::Win,
+    })
+}
+
+fn guide() -> impl Strategy<Value = String> {
+    prop::collection::vec(("[ABC]", "[XYZ]"), 1..100).prop_map(|rounds| {
+        rounds
+            .iter()
+            .map(|(opponent, response)| format!("{opponent} {response}\n"))
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn expected_result_is_reached(opponent in shape(), expected in result()) {
+        let player = Shape::from_expected_result(&opponent, &expected);
+        prop_assert_eq!(player.play_against(&opponent).points(), expected.points());
+    }
+
+    #[test]
+    fn results_are_opposite(a in shape(), b in shape()) {
+        prop_assert_eq!(a.play_against(&b).points() + b.play_against(&a).points(), 6);
+        prop_assert_eq!(a == b, a.play_against(&b).points() == 3);
+    }
+
+    #[test]
+    fn round_points_add_shape_and_result(player in shape(), opponent in shape()) {
+        let points = Round::new(player, opponent).points();
+        prop_assert_eq!(points, player.points() + player.play_against(&opponent).points());
+        prop_assert!((1..=9).contains(&points));
+    }
+
+    #[test]
+    fn totals_are_bounded_by_rounds(guide in guide()) {
+        let entries = RockPaperScissors::parse(&guide).unwrap();
+        let rounds = entries.len() as u32;
+        for total in [
+            RockPaperScissors::part1(&entries).unwrap(),
+            RockPaperScissors::part2(&entries).unwrap(),
+        ] {
+            prop_assert!((rounds..=rounds * 9).contains(&total));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_columns(opponent in "[A-Z]", response in "[A-Z]") {
+        let valid = "ABC".contains(&opponent) && "XYZ".contains(&response);
+        prop_assert_eq!(GuideEntry::parse(&format!("{opponent} {response}")).is_ok(), valid);
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::RucksackReorganization;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generator for RucksackReorganization {
+    /// Generates `size` rucksacks, rounded up to whole groups of three. Each
+    /// rucksack repeats exactly one item across its compartments and each
+    /// group shares exactly one badge
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut rucksacks = String::new();
+
+        for _ in 0..size.max(1).div_ceil(3) {
+            let mut items: Vec<char> = ITEMS.chars().collect();
+            rng.shuffle(&mut items);
+            let (badge, others) = items.split_last().expect("There are 52 items");
+
+            // Elves of a group never share any item besides the badge
+            for pool in others.chunks_exact(others.len() / 3) {
+                let (repeated, pool) = pool.split_first().expect("Pools are not empty");
+                let (pool_1, pool_2) = pool.split_at(pool.len() / 2);
+
+                let mut compartment_1 = vec![*repeated];
+                let mut compartment_2 = vec![*repeated];
+                if rng.below(2) == 0 {
+                    compartment_1.push(*badge);
+                } else {
+                    compartment_2.push(*badge);
+                }
+
+                let length = rng.range(2..=16) as usize;
+                for (compartment, pool) in
+                    [(&mut compartment_1, pool_1), (&mut compartment_2, pool_2)]
+                {
+                    while compartment.len() < length {
+                        compartment.push(*rng.pick(pool));
+                    }
+                    rng.shuffle(compartment);
+                    rucksacks.extend(compartment.iter());
+                }
+                rucksacks.push('\n');
+            }
+        }
+
+        rucksacks
+    }
+}
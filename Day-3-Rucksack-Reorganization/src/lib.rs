@@ -0,0 +1,176 @@
+//! Day 3: Rucksack Reorganization
+//!
+//! Finds the misplaced items in each rucksack and the badge shared by each
+//! group of three elves.
+
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    parse,
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde_json::{json, Value};
+
+mod generate;
+mod trace;
+
+pub use trace::Event;
+
+/// [`Solution`] for day 3
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<Rucksack>;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        parse::lines(input.trim_end())
+            .map(|line| Rucksack::parse(line.text).map_err(|e| e.on_line(line.number)))
+            .collect()
+    }
+
+    /// Returns the sum of the priorities of the item repeated in both
+    /// compartments of each rucksack
+    fn part1(rucksacks: &Self::Input) -> Result<u32> {
+        sum_repeated(rucksacks, &mut NoTrace)
+    }
+
+    /// Returns the sum of the priorities of the badge shared by each group of
+    /// three elves
+    fn part2(rucksacks: &Self::Input) -> Result<u32> {
+        sum_badges(rucksacks, &mut NoTrace)
+    }
+
+    /// Number of rucksacks and of groups of three elves
+    fn details(rucksacks: &Self::Input) -> Value {
+        json!({
+            "rucksacks": rucksacks.len(),
+            "groups": rucksacks.len() / 3,
+        })
+    }
+}
+
+/// Sums the priorities of the item repeated in each rucksack, sending each
+/// of them to `tracer`
+fn sum_repeated(rucksacks: &[Rucksack], tracer: &mut impl Tracer<Event>) -> Result<u32> {
+    let mut result = 0;
+    for (index, rucksack) in rucksacks.iter().enumerate() {
+        let repeated = rucksack
+            .find_repeated()
+            .ok_or_else(|| anyhow!("Did not found repeated in {}", rucksack.items))?;
+        let priority = get_char_priority(&repeated);
+        tracer.step(Event::Repeated {
+            index,
+            item: repeated,
+            priority,
+        });
+        result += priority;
+    }
+    Ok(result)
+}
+
+/// Sums the priorities of the badge of each group of three rucksacks, sending
+/// each of them to `tracer`
+fn sum_badges(rucksacks: &[Rucksack], tracer: &mut impl Tracer<Event>) -> Result<u32> {
+    let mut groups = 0;
+    for (index, group) in rucksacks.chunks_exact(3).enumerate() {
+        let badge = get_group_badge(&group[0], &group[1], &group[2])
+            .ok_or_else(|| anyhow!("Group does not share a badge"))?;
+        let priority = get_char_priority(&badge);
+        tracer.step(Event::Badge {
+            group: index,
+            item: badge,
+            priority,
+        });
+        groups += priority;
+    }
+    Ok(groups)
+}
+
+/// Gets priority from char based on its ASCII value
+/// # Panics
+/// If char is not alphabetic
+pub fn get_char_priority(c: &char) -> u32 {
+    match c {
+        'a'..='z' => *c as u32 - 96,
+        'A'..='Z' => *c as u32 - 65 + 27,
+        _ => panic!("Invalid item {}", c),
+    }
+}
+
+/// Finds a common `char` between the received Rucksacks
+pub fn get_group_badge(item1: &Rucksack, item2: &Rucksack, item3: &Rucksack) -> Option<char> {
+    item1.items.chars().find(|char1| {
+        item2.items.chars().any(|char2| *char1 == char2)
+            && item3.items.chars().any(|char3| *char1 == char3)
+    })
+}
+
+/// Items of a rucksack, split evenly between its two compartments
+#[derive(Debug)]
+pub struct Rucksack {
+    items: String,
+    middle: usize,
+}
+
+impl Rucksack {
+    /// Creates a rucksack from its list of items, which must be letters in
+    /// an even quantity
+    pub fn parse(input: &str) -> Result<Self, Diagnostic> {
+        if let Some((column, item)) = input
+            .chars()
+            .enumerate()
+            .find(|(_, item)| !item.is_ascii_alphabetic())
+        {
+            return Err(Diagnostic::at_column(
+                format!("Invalid item {item:?}"),
+                input,
+                column,
+                1,
+            ));
+        }
+
+        let len = input.len();
+        if !len.is_multiple_of(2) {
+            return Err(Diagnostic::new(
+                format!("Rucksack contents is not even quantity {}", len),
+                input,
+                input,
+            ));
+        }
+
+        Ok(Self {
+            items: input.to_owned(),
+            middle: len / 2,
+        })
+    }
+
+    /// All the items in the rucksack
+    pub fn items(&self) -> &str {
+        &self.items
+    }
+
+    /// Items in the first compartment
+    pub fn compartment_1(&self) -> &str {
+        &self.items[..self.middle]
+    }
+
+    /// Items in the second compartment
+    pub fn compartment_2(&self) -> &str {
+        &self.items[self.middle..]
+    }
+
+    /// Finds the item type present in both compartments
+    pub fn find_repeated(&self) -> Option<char> {
+        self.compartment_1()
+            .chars()
+            .find(|char1| self.compartment_2().chars().any(|char2| *char1 == char2))
+    }
+}
@@ -1,83 +1,33 @@
-use std::fs;
+use std::{path::Path, process::exit};
 
-fn main() {
-    let input = fs::read_to_string("src/input.txt").expect("Error reading input.txt");
-    let mut result = 0;
-    let mut groups = 0;
-    let mut group = Vec::new();
-
-    for line in input.lines() {
-        let rucksack = Rucksack::from(line);
-        let repeated = rucksack.find_repeated().expect("Did not found repeated");
-        let value = get_char_priority(&repeated);
-        result += value;
-
-        if group.len() < 2 {
-            group.push(rucksack);
-        } else {
-            group.push(rucksack);
-            let badge = get_group_badge(
-                group.pop().unwrap(),
-                group.pop().unwrap(),
-                group.pop().unwrap(),
-            )
-            .expect("Group does not share a badge");
-            groups += get_char_priority(&badge);
-        }
-    }
-
-    println!("Result: {} Groups: {}", result, groups);
-}
-
-/// Gets priority from char based on its ASCII value
-/// # Panics
-/// If char is not alphabetic
-fn get_char_priority(c: &char) -> u32 {
-    match c {
-        'a'..='z' => *c as u32 - 96,
-        'A'..='Z' => *c as u32 - 65 + 27,
-        _ => panic!("Invalid item {}", c),
-    }
-}
+use aoc_common::{input, report, Solution};
+use day_3_rucksack_reorganization::RucksackReorganization;
 
-/// Finds a common `char` between the received Rucksacks
-fn get_group_badge(item1: Rucksack, item2: Rucksack, item3: Rucksack) -> Option<char> {
-    item1.items.chars().find(|char1| {
-        item2.items.chars().any(|char2| *char1 == char2)
-            && item3.items.chars().any(|char3| *char1 == char3)
-    })
-}
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
 
-#[derive(Debug)]
-struct Rucksack {
-    items: String,
-    middle: usize,
-}
-
-impl Rucksack {
-    pub fn from(input: &str) -> Self {
-        let len = input.len();
-        if len % 2 != 0 {
-            panic!("Rucksack contents is not even quantity {}", len);
+fn main() {
+    let (args, input) = match input::from_args(Path::new(DEFAULT_INPUT)) {
+        Ok(read) => read,
+        Err(e) => {
+            eprintln!("{e:#}");
+            exit(1);
         }
-
-        Self {
-            items: input.to_owned(),
-            middle: len / 2,
+    };
+    let rucksacks = match RucksackReorganization::parse(&input) {
+        Ok(rucksacks) => rucksacks,
+        Err(e) => {
+            eprintln!("{e}");
+            exit(1);
         }
-    }
+    };
 
-    pub fn compartment_1(&self) -> &str {
-        &self.items[..self.middle]
+    if args.json {
+        report::print::<RucksackReorganization>(&rucksacks);
+        return;
     }
 
-    pub fn compartment_2(&self) -> &str {
-        &self.items[self.middle..]
-    }
+    let result = RucksackReorganization::part1(&rucksacks).expect("Did not found repeated");
+    let groups = RucksackReorganization::part2(&rucksacks).expect("Group does not share a badge");
 
-    pub fn find_repeated(&self) -> Option<char> {
-        self.compartment_1()
-            .chars()
-            .find(|char1| self.compartment_2().chars().any(|char2| *char1 == char2))
-    }
+    println!("Result: {} Groups: {}", result, groups);
 }
@@ -0,0 +1,45 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{sum_badges, sum_repeated, RucksackReorganization};
+
+/// Step of summing the priorities
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// Item found in both compartments of a rucksack
+    Repeated {
+        /// Position of the rucksack in the input, counted from 0
+        index: usize,
+        /// Repeated item
+        item: char,
+        /// Priority of the item
+        priority: u32,
+    },
+    /// Badge shared by a group of three rucksacks
+    Badge {
+        /// Position of the group, counted from 0
+        group: usize,
+        /// Badge item
+        item: char,
+        /// Priority of the item
+        priority: u32,
+    },
+}
+
+impl Trace for RucksackReorganization {
+    type Event = Event;
+
+    fn solve_traced(
+        rucksacks: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(sum_repeated(rucksacks, tracer)?.to_string()),
+            2 => Ok(sum_badges(rucksacks, tracer)?.to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_common::{trace::Trace, Solution};
+use day_3_rucksack_reorganization::{
+    get_char_priority, get_group_badge, Event, Rucksack, RucksackReorganization,
+};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let rucksacks = RucksackReorganization::parse(EXAMPLE).unwrap();
+    assert_eq!(RucksackReorganization::part1(&rucksacks).unwrap(), 157);
+    assert_eq!(RucksackReorganization::part2(&rucksacks).unwrap(), 70);
+}
+
+#[test]
+fn splits_compartments() {
+    let rucksack = Rucksack::parse("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+    assert_eq!(rucksack.compartment_1(), "vJrwpWtwJgWr");
+    assert_eq!(rucksack.compartment_2(), "hcsFMMfFFhFp");
+    assert_eq!(rucksack.find_repeated(), Some('p'));
+}
+
+#[test]
+fn prioritizes_items() {
+    assert_eq!(get_char_priority(&'a'), 1);
+    assert_eq!(get_char_priority(&'z'), 26);
+    assert_eq!(get_char_priority(&'A'), 27);
+    assert_eq!(get_char_priority(&'Z'), 52);
+}
+
+#[test]
+fn finds_group_badge() {
+    let rucksacks = RucksackReorganization::parse(EXAMPLE).unwrap();
+    let badge = get_group_badge(&rucksacks[0], &rucksacks[1], &rucksacks[2]);
+    assert_eq!(badge, Some('r'));
+}
+
+#[test]
+fn ignores_trailing_blank_lines() {
+    let rucksacks = RucksackReorganization::parse(&format!("{EXAMPLE}\n\n")).unwrap();
+    assert_eq!(rucksacks.len(), 6);
+    assert_eq!(RucksackReorganization::part1(&rucksacks).unwrap(), 157);
+}
+
+#[test]
+fn reports_odd_rucksack() {
+    let error = RucksackReorganization::parse("abAB\nabc\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.len), (2, 1, 3));
+}
+
+#[test]
+fn reports_invalid_item() {
+    let error = Rucksack::parse("ab1d").unwrap_err();
+    assert_eq!(error.column, 3);
+}
+
+#[test]
+fn traces_repeated_items_and_badges() {
+    let input = RucksackReorganization::parse(EXAMPLE).unwrap();
+
+    let mut steps = Vec::new();
+    let answer = RucksackReorganization::solve_traced(&input, 1, &mut steps).unwrap();
+    assert_eq!(answer, "157");
+    assert_eq!(
+        steps[0],
+        Event::Repeated {
+            index: 0,
+            item: 'p',
+            priority: 16
+        }
+    );
+
+    let mut steps = Vec::new();
+    RucksackReorganization::solve_traced(&input, 2, &mut steps).unwrap();
+    assert_eq!(
+        steps,
+        [
+            Event::Badge {
+                group: 0,
+                item: 'r',
+                priority: 18
+            },
+            Event::Badge {
+                group: 1,
+                item: 'Z',
+                priority: 52
+            }
+        ]
+    );
+}
@@ -0,0 +1,61 @@
+use aoc_common::Solution;
+use day_3_rucksack_reorganization::{
+    get_char_priority, get_group_badge, Rucksack, RucksackReorganization,
+};
+use proptest::prelude::*;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Rucksack whose compartments are guaranteed to share `repeated`
+fn rucksack() -> impl Strategy<Value = String> {
+    ("[a-zA-Z]", "[a-zA-Z]{0,15}", "[a-zA-Z]{0,15}").prop_map(|(repeated, first, second)| {
+        let length = first.len().max(second.len());
+        let pad = |half: &str| format!("{repeated}{half}{}", repeated.repeat(length - half.len()));
+        pad(&first) + &pad(&second)
+    })
+}
+
+proptest! {
+    #[test]
+    fn priorities_are_unique(a in 0..52usize, b in 0..52usize) {
+        let item = |i: usize| ITEMS.chars().nth(i).unwrap();
+        prop_assert!((1..=52).contains(&get_char_priority(&item(a))));
+        prop_assert_eq!(a == b, get_char_priority(&item(a)) == get_char_priority(&item(b)));
+    }
+
+    #[test]
+    fn compartments_split_items_evenly(items in "[a-zA-Z]{1,16}") {
+        let items = items.repeat(2);
+        let rucksack = Rucksack::parse(&items).unwrap();
+        prop_assert_eq!(rucksack.compartment_1().len(), rucksack.compartment_2().len());
+        prop_assert_eq!(format!("{}{}", rucksack.compartment_1(), rucksack.compartment_2()), items);
+    }
+
+    #[test]
+    fn rejects_odd_item_count(items in "[a-zA-Z]{0,20}") {
+        let items = format!("{items}a");
+        prop_assert_eq!(Rucksack::parse(&items).is_ok(), items.len() % 2 == 0);
+    }
+
+    #[test]
+    fn repeated_item_is_in_both_compartments(items in rucksack()) {
+        let rucksack = Rucksack::parse(&items).unwrap();
+        let repeated = rucksack.find_repeated().unwrap();
+        prop_assert!(rucksack.compartment_1().contains(repeated));
+        prop_assert!(rucksack.compartment_2().contains(repeated));
+    }
+
+    #[test]
+    fn badge_is_in_every_rucksack(group in prop::array::uniform3(rucksack())) {
+        let [a, b, c] = group.map(|items| Rucksack::parse(&items).unwrap());
+        let shared = a.items().chars().find(|item| b.items().contains(*item) && c.items().contains(*item));
+        prop_assert_eq!(get_group_badge(&a, &b, &c), shared);
+    }
+
+    #[test]
+    fn part1_sums_a_priority_per_rucksack(rucksacks in prop::collection::vec(rucksack(), 1..20)) {
+        let parsed = RucksackReorganization::parse(&rucksacks.join("\n")).unwrap();
+        let total = RucksackReorganization::part1(&parsed).unwrap();
+        prop_assert!((parsed.len() as u32..=parsed.len() as u32 * 52).contains(&total));
+    }
+}
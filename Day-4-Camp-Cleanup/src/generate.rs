@@ -0,0 +1,18 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::CampCleanup;
+
+impl Generator for CampCleanup {
+    /// Generates the assignments of `size` pairs of elves
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            let end = rng.range(start..=99);
+            format!("{start}-{end}")
+        };
+
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
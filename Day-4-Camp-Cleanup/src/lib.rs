@@ -0,0 +1,150 @@
+//! Day 4: Camp Cleanup
+//!
+//! Finds the pairs of elves whose assigned section ranges overlap.
+
+#![warn(missing_docs)]
+
+use core::fmt;
+
+use anyhow::Result;
+use aoc_common::{
+    parse::{self, Line},
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde_json::{json, Value};
+
+mod generate;
+mod trace;
+
+pub use trace::Event;
+
+/// [`Solution`] for day 4
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    /// Section assignments of each pair of elves
+    type Input = Vec<(Range, Range)>;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        parse::lines(input)
+            .map(|line| get_ranges(line.text).map_err(|e| e.on_line(line.number)))
+            .collect()
+    }
+
+    /// Counts the pairs where one range fully contains the other
+    fn part1(pairs: &Self::Input) -> Result<usize> {
+        Ok(count_pairs(pairs, fully_overlap, &mut NoTrace))
+    }
+
+    /// Counts the pairs where the ranges overlap at all
+    fn part2(pairs: &Self::Input) -> Result<usize> {
+        Ok(count_pairs(pairs, partially_overlap, &mut NoTrace))
+    }
+
+    /// Number of pairs of elves
+    fn details(pairs: &Self::Input) -> Value {
+        json!({ "pairs": pairs.len() })
+    }
+}
+
+/// Whether one range of the pair fully contains the other
+fn fully_overlap((range_1, range_2): &(Range, Range)) -> bool {
+    range_1.fully_contains(range_2) || range_2.fully_contains(range_1)
+}
+
+/// Whether the ranges of the pair share at least one section
+fn partially_overlap((range_1, range_2): &(Range, Range)) -> bool {
+    range_1.partially_contains(range_2)
+}
+
+/// Counts the pairs matching `overlap`, sending each pair to `tracer`
+fn count_pairs(
+    pairs: &[(Range, Range)],
+    overlap: fn(&(Range, Range)) -> bool,
+    tracer: &mut impl Tracer<Event>,
+) -> usize {
+    let mut count = 0;
+    for (index, pair) in pairs.iter().enumerate() {
+        let counted = overlap(pair);
+        tracer.step(Event::Pair {
+            index,
+            first: [pair.0 .0, pair.0 .1],
+            second: [pair.1 .0, pair.1 .1],
+            counted,
+        });
+        count += usize::from(counted);
+    }
+    count
+}
+
+/// Inclusive range of section IDs
+#[derive(Debug, PartialEq)]
+pub struct Range(pub i32, pub i32);
+
+/// Error produced while parsing a pair of ranges
+#[derive(Debug)]
+pub enum RangeError {
+    /// A range is missing its `-`
+    MissingBoundsSeparator,
+    /// A line is missing the `,` between its ranges
+    MissingPairSeparator,
+    /// A bound is not a number
+    InvalidBound,
+}
+
+impl std::error::Error for RangeError {}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::MissingBoundsSeparator => "Missing bounds separator (Hyphen \"-\")",
+            Self::MissingPairSeparator => "Missing pair separator (Comma \",\")",
+            Self::InvalidBound => "Could not parse bound into number",
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl Range {
+    /// Determines if `other` is fully inside this range
+    pub fn fully_contains(&self, other: &Range) -> bool {
+        self.0 <= other.0 && self.1 >= other.1
+    }
+
+    /// Determines if the ranges share at least one section
+    pub fn partially_contains(&self, other: &Range) -> bool {
+        if self.0 <= other.0 {
+            self.1 >= other.0
+        } else {
+            self.0 <= other.1
+        }
+    }
+}
+
+/// Parses the range in the `from-to` format found at `range` in `line`
+fn get_range(line: &Line, range: &str) -> Result<Range, Diagnostic> {
+    let (from, to) = line.split_once(range, '-', RangeError::MissingBoundsSeparator.to_string())?;
+    let bound = |bound: &str| {
+        bound
+            .parse()
+            .map_err(|_| line.error(RangeError::InvalidBound.to_string(), bound))
+    };
+
+    Ok(Range(bound(from)?, bound(to)?))
+}
+
+/// Parses a pair of comma separated ranges
+pub fn get_ranges(from_line: &str) -> Result<(Range, Range), Diagnostic> {
+    let line = Line::new(1, from_line);
+    let (first, second) =
+        line.split_once(from_line, ',', RangeError::MissingPairSeparator.to_string())?;
+
+    Ok((get_range(&line, first)?, get_range(&line, second)?))
+}
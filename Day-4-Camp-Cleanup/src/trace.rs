@@ -0,0 +1,38 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{count_pairs, fully_overlap, partially_overlap, CampCleanup};
+
+/// Step of counting the overlapping pairs
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// A pair of elves was checked
+    Pair {
+        /// Position of the pair in the input, counted from 0
+        index: usize,
+        /// Sections of the first elf, both ends included
+        first: [i32; 2],
+        /// Sections of the second elf, both ends included
+        second: [i32; 2],
+        /// Whether the pair counts towards the answer
+        counted: bool,
+    },
+}
+
+impl Trace for CampCleanup {
+    type Event = Event;
+
+    fn solve_traced(
+        pairs: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(count_pairs(pairs, fully_overlap, tracer).to_string()),
+            2 => Ok(count_pairs(pairs, partially_overlap, tracer).to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_common::{trace::Trace, Solution};
+use day_4_camp_cleanup::{get_ranges, CampCleanup, Event, Range};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let pairs = CampCleanup::parse(EXAMPLE).unwrap();
+    assert_eq!(CampCleanup::part1(&pairs).unwrap(), 2);
+    assert_eq!(CampCleanup::part2(&pairs).unwrap(), 4);
+}
+
+#[test]
+fn parses_ranges() {
+    assert_eq!(get_ranges("2-4,6-8").unwrap(), (Range(2, 4), Range(6, 8)));
+}
+
+#[test]
+fn checks_containment() {
+    assert!(Range(2, 8).fully_contains(&Range(3, 7)));
+    assert!(!Range(3, 7).fully_contains(&Range(2, 8)));
+
+    assert!(Range(5, 7).partially_contains(&Range(7, 9)));
+    assert!(Range(7, 9).partially_contains(&Range(5, 7)));
+    assert!(!Range(2, 4).partially_contains(&Range(6, 8)));
+}
+
+#[test]
+fn reports_missing_pair_separator() {
+    let error = get_ranges("2-4").unwrap_err();
+    assert_eq!(error.column, 4);
+}
+
+#[test]
+fn reports_invalid_bound() {
+    let error = CampCleanup::parse("2-4,6-8\n2-4,x-8\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.len), (2, 5, 1));
+}
+
+#[test]
+fn traces_counted_pairs() {
+    let input = CampCleanup::parse(EXAMPLE).unwrap();
+    let mut steps = Vec::new();
+    let answer = CampCleanup::solve_traced(&input, 1, &mut steps).unwrap();
+    assert_eq!(answer, "2");
+
+    let counted: Vec<usize> = steps
+        .iter()
+        .filter_map(|step| match step {
+            Event::Pair {
+                index,
+                counted: true,
+                ..
+            } => Some(*index),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(counted, [3, 4]);
+}
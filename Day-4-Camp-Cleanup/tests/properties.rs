@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use day_4_camp_cleanup::{get_ranges, Range};
+use proptest::prelude::*;
+
+fn range() -> impl Strategy<Value = Range> {
+    (1..100i32, 0..20i32).prop_map(|(start, length)| Range(start, start + length))
+}
+
+fn sections(range: &Range) -> HashSet<i32> {
+    (range.0..=range.1).collect()
+}
+
+proptest! {
+    #[test]
+    fn partial_overlap_is_symmetric(a in range(), b in range()) {
+        prop_assert_eq!(a.partially_contains(&b), b.partially_contains(&a));
+    }
+
+    #[test]
+    fn partial_overlap_shares_a_section(a in range(), b in range()) {
+        let shared = !sections(&a).is_disjoint(&sections(&b));
+        prop_assert_eq!(a.partially_contains(&b), shared);
+    }
+
+    #[test]
+    fn full_overlap_contains_every_section(a in range(), b in range()) {
+        prop_assert_eq!(a.fully_contains(&b), sections(&b).is_subset(&sections(&a)));
+        if a.fully_contains(&b) {
+            prop_assert!(a.partially_contains(&b));
+        }
+    }
+
+    #[test]
+    fn parses_formatted_pairs(a in range(), b in range()) {
+        let line = format!("{}-{},{}-{}", a.0, a.1, b.0, b.1);
+        prop_assert_eq!(get_ranges(&line).unwrap(), (a, b));
+    }
+
+    #[test]
+    fn rejects_non_numeric_bounds(bound in "[a-z]{1,4}", column in 0..4usize) {
+        let mut bounds: Vec<String> = ["2", "4", "6", "8"].map(String::from).to_vec();
+        bounds[column] = bound.clone();
+        let line = format!("{}-{},{}-{}", bounds[0], bounds[1], bounds[2], bounds[3]);
+
+        let error = get_ranges(&line).unwrap_err();
+        prop_assert_eq!(&line[error.column - 1..][..error.len], bound.as_str());
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::SupplyStacks;
+
+/// Stacks are labelled with a single digit
+const STACKS: usize = 9;
+
+impl Generator for SupplyStacks {
+    /// Generates a drawing of 9 stacks followed by `size` instructions, each
+    /// moving crates from a stack that holds at least that many, sometimes
+    /// onto the same stack
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let heights: Vec<usize> = (0..STACKS).map(|_| rng.range(1..=8) as usize).collect();
+        let tallest = heights.iter().copied().max().unwrap_or(0);
+
+        let mut input = String::new();
+        for row in (0..tallest).rev() {
+            let line: Vec<String> = heights
+                .iter()
+                .map(|height| {
+                    if row < *height {
+                        format!("[{}]", char::from(b'A' + rng.below(26) as u8))
+                    } else {
+                        "   ".to_string()
+                    }
+                })
+                .collect();
+            input += line.join(" ").trim_end();
+            input.push('\n');
+        }
+        let labels: Vec<String> = (1..=STACKS).map(|label| format!(" {label} ")).collect();
+        input += &labels.join(" ");
+        input += "\n\n";
+
+        let mut heights = heights;
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.below(STACKS);
+                if heights[from] > 0 {
+                    break from;
+                }
+            };
+            let to = rng.below(STACKS);
+            let amount = rng.range(1..=heights[from].min(10) as u64) as usize;
+
+            heights[from] -= amount;
+            heights[to] += amount;
+            input += &format!("move {amount} from {} to {}\n", from + 1, to + 1);
+        }
+
+        input
+    }
+}
@@ -1,9 +1,10 @@
-use anyhow::Result;
+use aoc_common::{parse::Line, Diagnostic};
 use core::fmt;
 
+/// Error produced while parsing an [`Instruction`]
 #[derive(Debug)]
 pub enum InstructionError {
-    InvalidAmount,
+    /// The instruction does not have the `move N from A to B` shape
     WrongNumberOfArguments(usize),
 }
 
@@ -12,7 +13,6 @@ impl std::error::Error for InstructionError {}
 impl fmt::Display for InstructionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let error = match self {
-            Self::InvalidAmount => "Invalid \"amount\"".to_string(),
             Self::WrongNumberOfArguments(args) => {
                 format!("Wrong number of arguments, found {args}, expected 6")
             }
@@ -22,28 +22,35 @@ impl fmt::Display for InstructionError {
     }
 }
 
+/// Crane instruction to move crates between two stacks
 #[derive(Debug)]
 pub struct Instruction {
+    /// Amount of crates to move
     pub amount: i32,
+    /// Label of the stack the crates are taken from
     pub from: String,
+    /// Label of the stack the crates are placed on
     pub to: String,
 }
 
 impl Instruction {
-    pub fn from(input: &str) -> Result<Self> {
-        let parts = input.split_ascii_whitespace().collect::<Vec<&str>>();
-        if parts.len() != 6 {
-            return Err(InstructionError::WrongNumberOfArguments(parts.len()))?;
+    /// Parses an instruction in the `move N from A to B` format
+    pub fn from(input: &str) -> Result<Self, Diagnostic> {
+        let line = Line::new(1, input);
+        let mut tokens = line.tokens();
+
+        let count = tokens.clone().count();
+        if count != 6 {
+            let error = InstructionError::WrongNumberOfArguments(count);
+            return Err(line.error(error.to_string(), input));
         }
 
-        let amount = match parts[1].parse::<i32>() {
-            Ok(amount) => amount,
-            Err(_) => return Err(InstructionError::InvalidAmount)?,
-        };
-
-        let from = parts[3].to_string();
-
-        let to = parts[5].to_string();
+        tokens.expect("move")?;
+        let amount = tokens.parse_next("amount")?;
+        tokens.expect("from")?;
+        let from = tokens.next_token("source stack")?.to_string();
+        tokens.expect("to")?;
+        let to = tokens.next_token("destination stack")?.to_string();
 
         Ok(Self { amount, from, to })
     }
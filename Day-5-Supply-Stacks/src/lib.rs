@@ -0,0 +1,131 @@
+//! Day 5: Supply Stacks
+//!
+//! Rearranges stacks of crates following the crane operator's instructions
+//! and reports the crate that ends up on top of each stack.
+
+#![warn(missing_docs)]
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+};
+
+use anyhow::Result;
+use aoc_common::{
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde_json::{json, Value};
+
+pub use crate::instruction::{Instruction, InstructionError};
+pub use crate::parser::{parse_input, Input};
+pub use crate::stack::{Stack, StackError};
+pub use crate::trace::Event;
+
+mod generate;
+mod instruction;
+mod parser;
+mod stack;
+mod trace;
+
+/// [`Solution`] for day 5
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Input;
+
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        parser::parse_input(input)
+    }
+
+    /// Rearranges the crates moving them one at a time and returns the label
+    /// of the crate at the top of each stack
+    fn part1(input: &Self::Input) -> Result<String> {
+        rearrange(input, move_one_at_a_time, &mut NoTrace)
+    }
+
+    /// Rearranges the crates moving each group at once, keeping their order,
+    /// and returns the label of the crate at the top of each stack
+    fn part2(input: &Self::Input) -> Result<String> {
+        rearrange(input, Stack::move_amount, &mut NoTrace)
+    }
+
+    /// Height of each stack before rearranging and number of instructions
+    fn details(input: &Self::Input) -> Value {
+        let stacks: BTreeMap<_, _> = input
+            .crates
+            .iter()
+            .map(|(label, stack)| (label, stack.borrow().len()))
+            .collect();
+
+        json!({
+            "stacks": stacks,
+            "instructions": input.instructions.len(),
+        })
+    }
+}
+
+/// Moves `amount` crates from `from` to `to` one at a time, which reverses
+/// their order
+fn move_one_at_a_time(from: &mut Stack, to: &mut Stack, amount: usize) -> Result<()> {
+    for _ in 0..amount {
+        from.move_item(to)?;
+    }
+    Ok(())
+}
+
+/// Applies every instruction to a copy of the crates with `move_crates` and
+/// returns the top crate of each stack, ordered by stack label. Each move is
+/// sent to `tracer`
+fn rearrange(
+    input: &Input,
+    move_crates: fn(&mut Stack, &mut Stack, usize) -> Result<()>,
+    tracer: &mut impl Tracer<Event>,
+) -> Result<String> {
+    let crates: HashMap<String, RefCell<Stack>> = input.crates.clone();
+
+    for instruction in &input.instructions {
+        if let (Some(from), Some(to)) = (crates.get(&instruction.from), crates.get(&instruction.to))
+        {
+            let amount = instruction.amount as usize;
+            let (from_height, to_height) = if instruction.from == instruction.to {
+                // The crates are lifted and put back on the same stack, which
+                // only fails when it holds too few of them
+                let mut stack = from.borrow_mut();
+                let mut lifted = Stack::new();
+                move_crates(&mut stack, &mut lifted, amount)?;
+                move_crates(&mut lifted, &mut stack, amount)?;
+                (stack.len(), stack.len())
+            } else {
+                let (mut from_stack, mut to_stack) = (from.borrow_mut(), to.borrow_mut());
+                move_crates(&mut from_stack, &mut to_stack, amount)?;
+                (from_stack.len(), to_stack.len())
+            };
+            tracer.step(Event::Move {
+                amount: instruction.amount,
+                from: instruction.from.clone(),
+                to: instruction.to.clone(),
+                from_height,
+                to_height,
+            });
+        }
+    }
+
+    let mut entries: Vec<(&String, &RefCell<Stack>)> = crates.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut output = String::new();
+    for entry in entries {
+        if let Some(top) = entry.1.borrow().peek() {
+            output += &top;
+        }
+    }
+
+    Ok(output)
+}
@@ -1,21 +1,20 @@
-use std::{cell::RefCell, fs, process::exit};
+use std::{path::Path, process::exit};
 
-use crate::stack::Stack;
+use aoc_common::{input, report, Solution};
+use day_5_supply_stacks::SupplyStacks;
 
-mod instruction;
-mod parser;
-mod stack;
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
 
 fn main() {
-    let input_text = match fs::read_to_string("./src/input.txt") {
-        Ok(input) => input,
+    let (args, input_text) = match input::from_args(Path::new(DEFAULT_INPUT)) {
+        Ok(read) => read,
         Err(e) => {
-            eprintln!("Could not read input file. Error: {e}");
+            eprintln!("Could not read input file. Error: {e:#}");
             exit(1);
         }
     };
 
-    let parsed_input = match parser::parse_input(&input_text) {
+    let parsed_input = match SupplyStacks::parse(&input_text) {
         Ok(input) => input,
         Err(e) => {
             eprintln!("Could not parse input. Error: {e}");
@@ -23,30 +22,21 @@ fn main() {
         }
     };
 
-    for instruction in parsed_input.instructions {
-        if let (Some(from), Some(to)) = (
-            parsed_input.crates.get(&instruction.from),
-            parsed_input.crates.get(&instruction.to),
-        ) {
-            if let Err(err) = from
-                .borrow_mut()
-                .move_amount(&mut to.borrow_mut(), instruction.amount as usize)
-            {
-                eprintln!("Could not move amount. Error: {err}");
-                exit(1);
-            }
-        }
+    if args.json {
+        report::print::<SupplyStacks>(&parsed_input);
+        return;
     }
 
-    let mut entries: Vec<(&String, &RefCell<Stack>)> = parsed_input.crates.iter().collect();
-    entries.sort_by(|a, b| a.0.cmp(b.0));
-
-    let mut output = String::new();
-    for entry in entries {
-        if let Some(top) = entry.1.borrow().peek() {
-            output += &top;
+    for (mover, solve) in [
+        ("CrateMover 9000", SupplyStacks::part1 as fn(&_) -> _),
+        ("CrateMover 9001", SupplyStacks::part2),
+    ] {
+        match solve(&parsed_input) {
+            Ok(output) => println!("Top items ({mover}) {:?}", output),
+            Err(e) => {
+                eprintln!("Could not move amount. Error: {e}");
+                exit(1);
+            }
         }
     }
-
-    println!("Top items {:?}", output);
 }
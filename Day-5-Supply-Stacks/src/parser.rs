@@ -1,12 +1,17 @@
 use std::{cell::RefCell, collections::HashMap, fmt};
 
 use crate::{instruction::Instruction, stack::Stack};
-use anyhow::Result;
+use aoc_common::{
+    parse::{self, Line},
+    Diagnostic,
+};
 
 #[derive(Debug)]
 enum ParseError {
     InvalidLabels,
     InvalidCrateLabel(String),
+    InvalidCrate(String),
+    UnlabeledCrate,
 }
 
 impl std::error::Error for ParseError {}
@@ -18,35 +23,43 @@ impl fmt::Display for ParseError {
             Self::InvalidCrateLabel(label) => {
                 format!("Input has invalid crate label: \"{label}\"")
             }
+            Self::InvalidCrate(content) => format!("Invalid crate \"{content}\""),
+            Self::UnlabeledCrate => String::from("Crate is not above a stack label"),
         };
         write!(f, "{error}")
     }
 }
 
+/// Starting stacks of crates and the instructions to rearrange them
 #[derive(Debug)]
 pub struct Input {
+    /// Instructions in the order they are performed
     pub instructions: Vec<Instruction>,
+    /// Stacks of crates by their label
     pub crates: HashMap<String, RefCell<Stack>>,
 }
 
-pub fn parse_input(input: &str) -> Result<Input> {
-    let mut is_crates = true;
-    let mut crates_lines = Vec::new();
-    let mut instructions = Vec::new();
+/// Parses the crates drawing followed by a blank line and the instructions
+pub fn parse_input(input: &str) -> Result<Input, Diagnostic> {
+    let mut lines = parse::lines(input);
+    let crates_lines: Vec<Line> = lines.by_ref().take_while(|line| !line.is_blank()).collect();
+    let crates = parse_crates(crates_lines)?;
 
-    for line in input.lines() {
-        if is_crates {
-            if line.trim().is_empty() {
-                is_crates = false;
-                continue;
+    let mut instructions = Vec::new();
+    for line in lines {
+        let instruction = Instruction::from(line.text).map_err(|e| e.on_line(line.number))?;
+
+        // `from` and `to` are the 4th and 6th words of the instruction
+        for (label, position) in [(&instruction.from, 3), (&instruction.to, 5)] {
+            if !crates.contains_key(label) {
+                let snippet = line.tokens().nth(position).unwrap_or(line.text);
+                let error = ParseError::InvalidCrateLabel(label.clone());
+                return Err(line.error(error.to_string(), snippet));
             }
-            crates_lines.push(line);
-        } else {
-            instructions.push(Instruction::from(line)?);
         }
-    }
 
-    let crates = parse_crates(crates_lines)?;
+        instructions.push(instruction);
+    }
 
     Ok(Input {
         instructions,
@@ -54,51 +67,62 @@ pub fn parse_input(input: &str) -> Result<Input> {
     })
 }
 
-#[derive(Debug)]
-struct CrateLabel {
-    label: String,
-    index: usize,
-}
-
-fn parse_crates(mut crates_lines: Vec<&str>) -> Result<HashMap<String, RefCell<Stack>>> {
-    let labels_line = crates_lines.pop().ok_or(ParseError::InvalidLabels)?;
-    let crate_labels = get_labels(labels_line);
+/// Width of a crate in the drawing, `[A]` and the space after it
+const CRATE_WIDTH: usize = 4;
+
+fn parse_crates(
+    mut crates_lines: Vec<Line>,
+) -> Result<HashMap<String, RefCell<Stack>>, Diagnostic> {
+    let labels_line = crates_lines
+        .pop()
+        .ok_or_else(|| Diagnostic::at_end(ParseError::InvalidLabels.to_string(), ""))?;
+    // Trailing spaces are no empty label
+    let labels_line = Line::new(labels_line.number, labels_line.text.trim_end());
+
+    let mut labels = Vec::new();
+    for cell in labels_line.columns(CRATE_WIDTH) {
+        let label = cell.trim();
+        if label.is_empty() {
+            return Err(labels_line.error(ParseError::InvalidLabels.to_string(), cell));
+        }
+        labels.push(label);
+    }
+    if labels.is_empty() {
+        return Err(labels_line.error_at_end(ParseError::InvalidLabels.to_string()));
+    }
 
-    let mut crates = HashMap::new();
+    let mut crates: HashMap<String, RefCell<Stack>> = labels
+        .iter()
+        .map(|label| (label.to_string(), RefCell::new(Stack::new())))
+        .collect();
 
     crates_lines.reverse();
     for line in crates_lines {
-        for label in crate_labels.iter() {
-            if !crates.contains_key(&label.label) {
-                crates.insert(label.label.clone(), RefCell::new(Stack::new()));
-            }
-            let crate_stack = crates
-                .get_mut(&label.label)
-                .ok_or(ParseError::InvalidCrateLabel(label.label.clone()))?;
-
-            if let Some(crate_content) = line.chars().nth(label.index) {
-                if crate_content.is_whitespace() {
-                    continue;
-                }
-                crate_stack.get_mut().push(crate_content.to_string());
+        for (index, cell) in line.columns(CRATE_WIDTH).enumerate() {
+            let content = cell.trim();
+            if content.is_empty() {
+                continue;
             }
-        }
-    }
 
-    Ok(crates)
-}
-
-fn get_labels(labels_line: &str) -> Vec<CrateLabel> {
-    let mut crate_labels = Vec::new();
-
-    for (index, label) in labels_line.chars().enumerate() {
-        if !label.is_whitespace() {
-            crate_labels.push(CrateLabel {
-                label: label.to_string(),
-                index,
-            });
+            let label = labels
+                .get(index)
+                .ok_or_else(|| line.error(ParseError::UnlabeledCrate.to_string(), content))?;
+            let name = content
+                .strip_prefix('[')
+                .and_then(|content| content.strip_suffix(']'))
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| {
+                    let error = ParseError::InvalidCrate(content.to_string());
+                    line.error(error.to_string(), content)
+                })?;
+
+            crates
+                .get_mut(*label)
+                .expect("Every label has a stack")
+                .get_mut()
+                .push(name.to_string());
         }
     }
 
-    crate_labels
+    Ok(crates)
 }
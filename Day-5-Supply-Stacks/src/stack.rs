@@ -1,8 +1,10 @@
 use anyhow::Result;
 use std::fmt;
 
+/// Error produced while moving crates
 #[derive(Debug)]
 pub enum StackError {
+    /// Tried to take a crate from an empty stack
     StackEmpty,
 }
 impl std::error::Error for StackError {}
@@ -16,29 +18,41 @@ impl fmt::Display for StackError {
     }
 }
 
-#[derive(Debug)]
+/// Stack of crates, the last item is the one on top
+#[derive(Debug, Clone, Default)]
 pub struct Stack {
     items: Vec<String>,
 }
 
 impl Stack {
+    /// Creates an empty stack
     pub fn new() -> Self {
         Self { items: Vec::new() }
     }
 
+    /// Number of crates in the stack
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the stack has no crates
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Places a crate on top of the stack
     pub fn push(&mut self, value: String) {
         self.items.push(value);
     }
 
+    /// Takes the crate on top of the stack
     pub fn pop(&mut self) -> Option<String> {
         self.items.pop()
     }
 
+    /// Returns the crate on top of the stack without taking it
     pub fn peek(&self) -> Option<String> {
-        match self.items.last() {
-            Some(val) => Some(val.clone()),
-            None => None,
-        }
+        self.items.last().cloned()
     }
 
     /// Moves an item from `self` to `destination`
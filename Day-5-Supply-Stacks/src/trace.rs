@@ -0,0 +1,40 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{move_one_at_a_time, rearrange, Stack, SupplyStacks};
+
+/// Step of rearranging the crates
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// The crane moved crates between two stacks
+    Move {
+        /// Amount of crates moved
+        amount: i32,
+        /// Label of the stack the crates were taken from
+        from: String,
+        /// Label of the stack the crates were placed on
+        to: String,
+        /// Crates left on the `from` stack
+        from_height: usize,
+        /// Crates on the `to` stack after the move
+        to_height: usize,
+    },
+}
+
+impl Trace for SupplyStacks {
+    type Event = Event;
+
+    fn solve_traced(
+        input: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => rearrange(input, move_one_at_a_time, tracer),
+            2 => rearrange(input, Stack::move_amount, tracer),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
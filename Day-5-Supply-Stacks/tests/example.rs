@@ -0,0 +1,107 @@
+use aoc_common::{trace::Trace, Solution};
+use day_5_supply_stacks::{parse_input, Event, Instruction, Stack, SupplyStacks};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let input = SupplyStacks::parse(EXAMPLE).unwrap();
+    assert_eq!(SupplyStacks::part1(&input).unwrap(), "CMZ");
+    assert_eq!(SupplyStacks::part2(&input).unwrap(), "MCD");
+}
+
+#[test]
+fn ignores_trailing_spaces_after_labels() {
+    let input = SupplyStacks::parse(include_str!("fixtures/trailing_spaces.txt")).unwrap();
+    assert_eq!(input.crates.len(), 3);
+    assert_eq!(SupplyStacks::part1(&input).unwrap(), "CMZ");
+}
+
+#[test]
+fn parses_crates_and_instructions() {
+    let input = parse_input(EXAMPLE).unwrap();
+
+    assert_eq!(input.crates.len(), 3);
+    assert_eq!(input.crates["1"].borrow().peek().as_deref(), Some("N"));
+    assert_eq!(input.crates["2"].borrow().peek().as_deref(), Some("D"));
+    assert_eq!(input.crates["3"].borrow().peek().as_deref(), Some("P"));
+
+    assert_eq!(input.instructions.len(), 4);
+    assert_eq!(input.instructions[1].amount, 3);
+    assert_eq!(input.instructions[1].from, "1");
+    assert_eq!(input.instructions[1].to, "3");
+}
+
+#[test]
+fn moves_crates_keeping_order() {
+    let mut from = Stack::new();
+    for item in ["A", "B", "C"] {
+        from.push(item.to_string());
+    }
+    let mut to = Stack::new();
+
+    from.move_amount(&mut to, 2).unwrap();
+
+    assert_eq!(to.pop().as_deref(), Some("C"));
+    assert_eq!(to.pop().as_deref(), Some("B"));
+    assert_eq!(from.peek().as_deref(), Some("A"));
+}
+
+#[test]
+fn fails_to_move_from_empty_stack() {
+    let mut from = Stack::new();
+    let mut to = Stack::new();
+    assert!(from.move_item(&mut to).is_err());
+}
+
+#[test]
+fn moves_crates_onto_their_own_stack() {
+    // Lifting the crates of stack 2 and putting them back changes nothing
+    let input = EXAMPLE.replacen("move", "move 3 from 2 to 2\nmove", 1);
+    let input = SupplyStacks::parse(&input).unwrap();
+    assert_eq!(input.instructions.len(), 5);
+    assert_eq!(SupplyStacks::part1(&input).unwrap(), "CMZ");
+    assert_eq!(SupplyStacks::part2(&input).unwrap(), "MCD");
+
+    let too_many = EXAMPLE.replacen("move", "move 4 from 2 to 2\nmove", 1);
+    let input = SupplyStacks::parse(&too_many).unwrap();
+    assert!(SupplyStacks::part1(&input).is_err());
+    assert!(SupplyStacks::part2(&input).is_err());
+}
+
+#[test]
+fn reports_invalid_instruction() {
+    let error = Instruction::from("move 1 from 2").unwrap_err();
+    assert_eq!(error.len, "move 1 from 2".len());
+
+    let input = EXAMPLE.replace("move 3 from 1", "move x from 1");
+    let error = parse_input(&input).unwrap_err();
+    assert_eq!((error.line, error.column), (7, 6));
+}
+
+#[test]
+fn reports_unknown_stack() {
+    let input = EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 7");
+    let error = parse_input(&input).unwrap_err();
+    assert_eq!((error.line, error.column), (9, 18));
+}
+
+#[test]
+fn traces_moves() {
+    let input = SupplyStacks::parse(EXAMPLE).unwrap();
+    let mut steps = Vec::new();
+    let answer = SupplyStacks::solve_traced(&input, 1, &mut steps).unwrap();
+    assert_eq!(answer, "CMZ");
+
+    assert_eq!(steps.len(), 4);
+    assert_eq!(
+        steps[0],
+        Event::Move {
+            amount: 1,
+            from: "2".to_string(),
+            to: "1".to_string(),
+            from_height: 2,
+            to_height: 3
+        }
+    );
+}
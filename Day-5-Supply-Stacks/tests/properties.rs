@@ -0,0 +1,81 @@
+use aoc_common::Solution;
+use day_5_supply_stacks::{Instruction, Stack, SupplyStacks};
+use proptest::prelude::*;
+
+fn stack(crates: &[String]) -> Stack {
+    let mut stack = Stack::new();
+    for label in crates {
+        stack.push(label.clone());
+    }
+    stack
+}
+
+fn crates() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec("[A-Z]", 0..20)
+}
+
+fn drain(stack: &mut Stack) -> Vec<String> {
+    let mut crates = Vec::new();
+    while let Some(label) = stack.pop() {
+        crates.push(label);
+    }
+    crates.reverse();
+    crates
+}
+
+proptest! {
+    #[test]
+    fn move_amount_conserves_crates(from in crates(), to in crates(), amount in 0..25usize) {
+        let (mut source, mut destination) = (stack(&from), stack(&to));
+        let moved = source.move_amount(&mut destination, amount);
+
+        prop_assert_eq!(moved.is_ok(), amount <= from.len());
+        if moved.is_ok() {
+            prop_assert_eq!(source.len() + destination.len(), from.len() + to.len());
+
+            // The moved crates keep their order
+            let mut expected = to.clone();
+            expected.extend_from_slice(&from[from.len() - amount..]);
+            prop_assert_eq!(drain(&mut destination), expected);
+            prop_assert_eq!(drain(&mut source), &from[..from.len() - amount]);
+        }
+    }
+
+    #[test]
+    fn move_item_reverses_crates(from in crates(), amount in 0..20usize) {
+        prop_assume!(amount <= from.len());
+        let (mut source, mut destination) = (stack(&from), Stack::new());
+        for _ in 0..amount {
+            source.move_item(&mut destination).unwrap();
+        }
+
+        let mut expected = from[from.len() - amount..].to_vec();
+        expected.reverse();
+        prop_assert_eq!(drain(&mut destination), expected);
+    }
+
+    #[test]
+    fn parses_formatted_instructions(amount in 0..1_000i32, from in "[1-9]", to in "[1-9]") {
+        let instruction = Instruction::from(&format!("move {amount} from {from} to {to}")).unwrap();
+        prop_assert_eq!(instruction.amount, amount);
+        prop_assert_eq!(instruction.from, from);
+        prop_assert_eq!(instruction.to, to);
+    }
+
+    #[test]
+    fn cranes_agree_on_single_crate_moves(moves in prop::collection::vec((0..3usize, 0..3usize), 0..30)) {
+        // Every stack starts with enough crates for all the moves
+        let mut input = String::new();
+        for row in 0..moves.len().max(1) {
+            input += &format!("[{0}] [{0}] [{0}]\n", char::from(b'A' + (row % 26) as u8));
+        }
+        input += " 1   2   3 \n\n";
+        // Some moves put the crate back on the stack it came from
+        for (from, to) in &moves {
+            input += &format!("move 1 from {} to {}\n", from + 1, to + 1);
+        }
+
+        let parsed = SupplyStacks::parse(&input).unwrap();
+        prop_assert_eq!(SupplyStacks::part1(&parsed).unwrap(), SupplyStacks::part2(&parsed).unwrap());
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::TuningTrouble;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+impl Generator for TuningTrouble {
+    /// Generates a datastream of `size` characters, at least 14. The first
+    /// half only uses 3 letters and the second half 13 letters, so the packet
+    /// marker is found near the middle and the message marker at the very end
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(14);
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+
+        let mut datastream: Vec<u8> = Vec::with_capacity(size);
+        let filler = size - 14;
+        for i in 0..filler {
+            let alphabet = if i < filler / 2 { 3 } else { 13 };
+            datastream.push(letters[rng.below(alphabet)]);
+        }
+
+        let mut marker = letters[..14].to_vec();
+        rng.shuffle(&mut marker);
+        datastream.extend(marker);
+
+        String::from_utf8(datastream).expect("Letters are ASCII") + "\n"
+    }
+}
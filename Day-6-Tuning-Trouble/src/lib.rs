@@ -0,0 +1,141 @@
+//! Day 6: Tuning Trouble
+//!
+//! Finds the start-of-packet and start-of-message markers in the
+//! communication device's datastream.
+
+#![warn(missing_docs)]
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde_json::{json, Value};
+
+mod generate;
+mod trace;
+
+pub use trace::Event;
+
+/// [`Solution`] for day 6
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    /// The datastream buffer
+    type Input = String;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        Ok(input.trim().to_string())
+    }
+
+    /// Returns the number of characters processed before the first
+    /// start-of-packet marker (4 distinct characters) is complete
+    fn part1(datastream: &Self::Input) -> Result<usize> {
+        packet_marker(datastream, &mut NoTrace)
+    }
+
+    /// Returns the number of characters processed before the first
+    /// start-of-message marker (14 distinct characters) is complete
+    fn part2(datastream: &Self::Input) -> Result<usize> {
+        message_marker(datastream, &mut NoTrace)
+    }
+
+    /// Length of the datastream
+    fn details(datastream: &Self::Input) -> Value {
+        json!({ "length": datastream.chars().count() })
+    }
+}
+
+/// Position of the start-of-packet marker
+fn packet_marker(datastream: &str, tracer: &mut impl Tracer<Event>) -> Result<usize> {
+    find_marker_traced(datastream, 4, tracer)
+        .ok_or_else(|| anyhow!("String has no packet start marker"))
+}
+
+/// Position of the start-of-message marker
+fn message_marker(datastream: &str, tracer: &mut impl Tracer<Event>) -> Result<usize> {
+    find_marker_traced(datastream, 14, tracer)
+        .ok_or_else(|| anyhow!("String has no message start marker"))
+}
+
+/// Returns the number of characters processed until the last `size` characters
+/// are all different
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+    find_marker_traced(input, size, &mut NoTrace)
+}
+
+/// [`find_marker`], sending each slide of the window to `tracer`
+fn find_marker_traced(input: &str, size: usize, tracer: &mut impl Tracer<Event>) -> Option<usize> {
+    let mut seq = CharacterSequence::new(size);
+
+    for (i, character) in input.chars().enumerate() {
+        seq.add(character);
+
+        let unique = seq.is_filled() && seq.values_unique();
+        tracer.step(Event::Slide {
+            position: i + 1,
+            character,
+            unique,
+        });
+
+        if unique {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+/// Sliding window over the last characters of the datastream
+#[derive(Debug)]
+pub struct CharacterSequence {
+    values: Vec<char>,
+    size: usize,
+    pointer: usize,
+}
+
+impl CharacterSequence {
+    /// Instantiates a CharacterSequence with the received size
+    pub fn new(size: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(size),
+            size,
+            pointer: 0,
+        }
+    }
+
+    /// Adds a value to the sequence, if sequence is at maximum capacity
+    /// the oldest item is replaced
+    pub fn add(&mut self, value: char) {
+        if self.values.len() < self.size {
+            self.values.push(value);
+        } else {
+            self.values[self.pointer] = value;
+        }
+
+        if self.pointer < self.size - 1 {
+            self.pointer += 1;
+        } else {
+            self.pointer = 0;
+        }
+    }
+
+    /// Determines if the values in the sequence are all unique
+    pub fn values_unique(&self) -> bool {
+        let mut uniq = HashSet::new();
+        self.values.iter().all(|x| uniq.insert(x))
+    }
+
+    /// Determines if the sequence has its whole size occupied
+    pub fn is_filled(&self) -> bool {
+        self.values.len() == self.size
+    }
+}
@@ -1,72 +1,38 @@
-use std::{collections::HashSet, fs};
+use std::{path::Path, process::exit};
 
-fn main() {
-    let input = fs::read_to_string("./src/input.txt").expect("Unable to read file");
-
-    let mut seq = CharacterSequence::new(14);
-    let mut packet_marker: Option<usize> = None;
-
-    for (i, character) in input.chars().enumerate() {
-        seq.add(character);
-
-        if !seq.is_filled() {
-            continue;
-        }
-
-        if seq.values_unique() {
-            packet_marker = Some(i);
-            break;
-        }
-    }
+use aoc_common::{input, report, Solution};
+use day_6_tuning_trouble::TuningTrouble;
 
-    if let Some(index) = packet_marker {
-        println!("Packet starts at index {}", index + 1);
-    } else {
-        println!("String has no packet start marker");
-    }
-}
-
-#[derive(Debug)]
-struct CharacterSequence {
-    values: Vec<char>,
-    size: usize,
-    pointer: usize,
-}
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
 
-impl CharacterSequence {
-    /// Instantiates a CharacterSequence with the received size
-    pub fn new(size: usize) -> Self {
-        Self {
-            values: Vec::with_capacity(size),
-            size,
-            pointer: 0,
+fn main() {
+    let (args, input) = match input::from_args(Path::new(DEFAULT_INPUT)) {
+        Ok(read) => read,
+        Err(e) => {
+            eprintln!("{e:#}");
+            exit(1);
         }
-    }
-
-    /// Adds a value to the sequence, if sequence is at maximum capacity
-    /// the oldest item is replaced
-    pub fn add(&mut self, value: char) {
-        if self.values.len() < self.size {
-            self.values.push(value);
-        } else {
-            self.values[self.pointer] = value;
+    };
+    let datastream = match TuningTrouble::parse(&input) {
+        Ok(datastream) => datastream,
+        Err(e) => {
+            eprintln!("{e}");
+            exit(1);
         }
+    };
 
-        if self.pointer < self.size - 1 {
-            self.pointer += 1;
-        } else {
-            self.pointer = 0;
-        }
+    if args.json {
+        report::print::<TuningTrouble>(&datastream);
+        return;
     }
 
-    /// Determines if the values in the sequence are all unique
-    pub fn values_unique(&self) -> bool {
-        let mut uniq = HashSet::new();
-        self.values.iter().all(|x| uniq.insert(x))
+    match TuningTrouble::part1(&datastream) {
+        Ok(index) => println!("Packet starts at index {}", index),
+        Err(e) => println!("{e}"),
     }
 
-    /// Determines if the sequence has its whole size occupied
-    pub fn is_filled(&self) -> bool {
-        self.values.len() == self.size
+    match TuningTrouble::part2(&datastream) {
+        Ok(index) => println!("Message starts at index {}", index),
+        Err(e) => println!("{e}"),
     }
 }
@@ -0,0 +1,37 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{message_marker, packet_marker, TuningTrouble};
+
+/// Step of looking for a marker
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// The window slid over one more character
+    Slide {
+        /// Characters processed so far
+        position: usize,
+        /// Character added to the window
+        character: char,
+        /// Whether the window is full and its characters are all different,
+        /// which ends the search
+        unique: bool,
+    },
+}
+
+impl Trace for TuningTrouble {
+    type Event = Event;
+
+    fn solve_traced(
+        datastream: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(packet_marker(datastream, tracer)?.to_string()),
+            2 => Ok(message_marker(datastream, tracer)?.to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_common::{trace::Trace, Solution};
+use day_6_tuning_trouble::{find_marker, CharacterSequence, Event, TuningTrouble};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let datastream = TuningTrouble::parse(EXAMPLE).unwrap();
+    assert_eq!(TuningTrouble::part1(&datastream).unwrap(), 7);
+    assert_eq!(TuningTrouble::part2(&datastream).unwrap(), 19);
+}
+
+#[test]
+fn finds_markers() {
+    let examples = [
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    for (datastream, packet, message) in examples {
+        assert_eq!(find_marker(datastream, 4), Some(packet), "{datastream}");
+        assert_eq!(find_marker(datastream, 14), Some(message), "{datastream}");
+    }
+}
+
+#[test]
+fn fails_without_marker() {
+    let datastream = TuningTrouble::parse("abcabcabc").unwrap();
+    assert!(TuningTrouble::part1(&datastream).is_err());
+}
+
+#[test]
+fn slides_character_window() {
+    let mut seq = CharacterSequence::new(3);
+    for c in "aab".chars() {
+        seq.add(c);
+    }
+    assert!(seq.is_filled());
+    assert!(!seq.values_unique());
+
+    seq.add('c');
+    assert!(seq.values_unique());
+}
+
+#[test]
+fn traces_window_slides() {
+    let input = TuningTrouble::parse("mjqjpqmgbljsphjdztnvjfqwrcgsmlb").unwrap();
+    let mut steps = Vec::new();
+    let answer = TuningTrouble::solve_traced(&input, 1, &mut steps).unwrap();
+    assert_eq!(answer, "7");
+
+    assert_eq!(steps.len(), 7);
+    assert_eq!(
+        steps[6],
+        Event::Slide {
+            position: 7,
+            character: 'm',
+            unique: true
+        }
+    );
+}
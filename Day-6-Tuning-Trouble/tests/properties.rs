@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+
+use day_6_tuning_trouble::{find_marker, CharacterSequence};
+use proptest::prelude::*;
+
+fn distinct(window: &[char]) -> bool {
+    window.iter().collect::<HashSet<_>>().len() == window.len()
+}
+
+proptest! {
+    #[test]
+    fn marker_is_first_distinct_window(datastream in "[a-h]{0,60}", size in 1..8usize) {
+        let chars: Vec<char> = datastream.chars().collect();
+        let expected = chars.windows(size).position(distinct).map(|start| start + size);
+
+        let marker = find_marker(&datastream, size);
+        prop_assert_eq!(marker, expected);
+        if let Some(end) = marker {
+            prop_assert!(distinct(&chars[end - size..end]));
+        }
+    }
+
+    #[test]
+    fn sequence_tracks_last_window(datastream in "[a-f]{1,40}", size in 1..6usize) {
+        let chars: Vec<char> = datastream.chars().collect();
+        let mut sequence = CharacterSequence::new(size);
+
+        for (i, character) in chars.iter().enumerate() {
+            sequence.add(*character);
+
+            let window = &chars[(i + 1).saturating_sub(size)..=i];
+            prop_assert_eq!(sequence.is_filled(), window.len() == size);
+            prop_assert_eq!(sequence.values_unique(), distinct(window));
+        }
+    }
+}
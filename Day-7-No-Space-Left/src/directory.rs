@@ -3,6 +3,7 @@ use anyhow::Result;
 
 use std::rc::{Rc, Weak};
 
+/// Directory inode, owns the items inside of it
 #[derive(Debug)]
 pub struct Directory {
     name: String,
@@ -11,6 +12,7 @@ pub struct Directory {
 }
 
 impl Directory {
+    /// Creates an empty directory
     pub fn new(name: &str, parent: Option<INodeWeak>) -> Self {
         Self {
             name: name.to_string(),
@@ -25,7 +27,7 @@ impl INode for Directory {
         &self.name
     }
 
-    fn size(&self) -> u32 {
+    fn size(&self) -> u64 {
         self.items.iter().map(|item| item.borrow().size()).sum()
     }
 
@@ -34,7 +36,7 @@ impl INode for Directory {
     }
 
     fn parent(&self) -> Option<INodeWeak> {
-        self.parent.as_ref().map(|parent| Weak::clone(parent))
+        self.parent.as_ref().map(Weak::clone)
     }
 
     fn set_parent(&mut self, parent: Option<INodeWeak>) {
@@ -42,7 +44,7 @@ impl INode for Directory {
     }
 
     fn items(&self) -> Option<Vec<INodeWeak>> {
-        Some(self.items.iter().map(|item| Rc::downgrade(item)).collect())
+        Some(self.items.iter().map(Rc::downgrade).collect())
     }
 
     fn add_item(&mut self, item: INodeRef) -> Result<()> {
@@ -8,6 +8,7 @@ use crate::inode::{INode, INodeWeak};
 #[error("Not a directory")]
 struct NotADirectoryError;
 
+/// File inode
 pub struct File {
     name: String,
     size: u32,
@@ -15,6 +16,7 @@ pub struct File {
 }
 
 impl File {
+    /// Creates a file of `size` bytes
     pub fn new(name: &str, size: u32, parent: Option<INodeWeak>) -> Self {
         Self {
             name: name.to_string(),
@@ -22,8 +24,6 @@ impl File {
             parent,
         }
     }
-
-    // pub fn
 }
 
 impl INode for File {
@@ -35,12 +35,12 @@ impl INode for File {
         &self.name
     }
 
-    fn size(&self) -> u32 {
-        self.size
+    fn size(&self) -> u64 {
+        u64::from(self.size)
     }
 
     fn parent(&self) -> Option<INodeWeak> {
-        self.parent.as_ref().map(|parent| Weak::clone(&parent))
+        self.parent.as_ref().map(Weak::clone)
     }
 
     fn set_parent(&mut self, parent: Option<INodeWeak>) {
@@ -9,32 +9,42 @@ use std::{
 };
 use thiserror::Error;
 
+/// File system being explored, keeps track of the current directory
 #[derive(Debug)]
 pub struct FileSystem {
     root: INodeRef,
     location: INodeRef,
 }
 
+/// Target of a `cd` command
 #[derive(Debug)]
 pub enum DirectoryChange {
+    /// `cd /`
     Root,
+    /// `cd ..`
     Parent,
+    /// `cd <name>`, a directory inside the current one
     Relative(String),
 }
 
+/// Error produced while navigating the file system
 #[derive(Error, Debug)]
 pub enum FileSystemError {
+    /// Tried to leave the root directory
     #[error("No parent directory")]
     NoParent,
 
+    /// The current directory has no item with the given name
     #[error("Directory not found: {0}")]
     DirectoryNotFound(String),
 
+    /// The parent inode was dropped
     #[error("Dead reference")]
     DeadReference,
 }
 
 impl FileSystem {
+    /// Creates a file system with an empty root directory as the current one
     pub fn new() -> Self {
         // Root directory of the file system
         let root_dir = Directory::new("/", None);
@@ -45,6 +55,7 @@ impl FileSystem {
         Self { root, location }
     }
 
+    /// Changes the current directory
     pub fn change_directory(&mut self, to: &DirectoryChange) -> Result<()> {
         match to {
             DirectoryChange::Parent => self.change_to_parent(),
@@ -84,6 +95,7 @@ impl FileSystem {
         Ok(())
     }
 
+    /// Adds an item to the current directory
     pub fn add_item(&mut self, item: INodeRef) -> Result<()> {
         let current_inode = &self.location;
 
@@ -96,12 +108,18 @@ impl FileSystem {
     }
 }
 
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl INode for FileSystem {
     fn name(&self) -> &str {
         "file_system_root"
     }
 
-    fn size(&self) -> u32 {
+    fn size(&self) -> u64 {
         self.root.borrow().size()
     }
 
@@ -0,0 +1,75 @@
+use std::fmt::Write;
+
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{NoSpaceLeft, DISK_SIZE, REQUIRED_UPDATE_SIZE};
+
+impl Generator for NoSpaceLeft {
+    /// Generates the transcript of exploring `size` directories, at least 2
+    /// including the root. Directory nesting is random and file sizes are
+    /// scaled so the update always needs some space freed
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(2);
+
+        // Each directory after the root hangs from a random earlier one
+        let mut children = vec![Vec::new(); size];
+        for directory in 1..size {
+            children[rng.below(directory)].push(directory);
+        }
+
+        let mut weights: Vec<Vec<u64>> = (0..size)
+            .map(|_| {
+                (0..rng.range(0..=4))
+                    .map(|_| rng.range(1..=1_000))
+                    .collect()
+            })
+            .collect();
+        if weights.iter().all(Vec::is_empty) {
+            weights[0].push(1);
+        }
+        let total_weight: u64 = weights.iter().flatten().sum();
+        let files = weights.iter().map(Vec::len).sum::<usize>() as u64;
+
+        // The first directory gets a file of the whole update size, so it can
+        // always be deleted. The other files share the rest of the used space,
+        // rounding each of them up to 1 byte adds up to `files` bytes
+        let update = REQUIRED_UPDATE_SIZE;
+        let minimum = DISK_SIZE - 2 * update + 1_000_000;
+        let maximum = (DISK_SIZE - update - 1_000_000)
+            .saturating_sub(files)
+            .max(minimum);
+        let target = rng.range(minimum..=maximum);
+        let scale = |weight: &u64| (weight * target / total_weight).max(1);
+
+        let mut transcript = String::from("$ cd /\n");
+        // Directories still to list, `None` marks going back up
+        let mut pending = vec![Some(0)];
+        while let Some(entry) = pending.pop() {
+            let Some(directory) = entry else {
+                transcript += "$ cd ..\n";
+                continue;
+            };
+            if directory != 0 {
+                writeln!(transcript, "$ cd d{directory}").expect("Writing to a String");
+            }
+
+            transcript += "$ ls\n";
+            for child in &children[directory] {
+                writeln!(transcript, "dir d{child}").expect("Writing to a String");
+            }
+            if directory == 1 {
+                writeln!(transcript, "{update} update.bin").expect("Writing to a String");
+            }
+            for (i, weight) in weights[directory].iter().enumerate() {
+                writeln!(transcript, "{} f{i}.txt", scale(weight)).expect("Writing to a String");
+            }
+
+            for child in children[directory].iter().rev() {
+                pending.push(None);
+                pending.push(Some(*child));
+            }
+        }
+
+        transcript
+    }
+}
@@ -6,13 +6,15 @@ use std::{
     rc::{Rc, Weak},
 };
 
+/// Shared reference to an inode
 pub type INodeRef = Rc<RefCell<dyn INode>>;
+/// Weak reference to an inode, used to point back to the parent
 pub type INodeWeak = Weak<RefCell<dyn INode>>;
 
 /// File system inode
 pub trait INode {
     /// Returns the `size` of the inode in **bytes**
-    fn size(&self) -> u32;
+    fn size(&self) -> u64;
 
     /// Returns the name of the inode
     fn name(&self) -> &str;
@@ -0,0 +1,173 @@
+//! Day 7: No Space Left On Device
+//!
+//! Rebuilds a file system from a terminal transcript of `cd` and `ls`
+//! commands and finds which directories to delete to free up space.
+
+#![warn(missing_docs)]
+
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde_json::{json, Value};
+
+pub use crate::directory::Directory;
+pub use crate::file::File;
+pub use crate::file_system::{DirectoryChange, FileSystem, FileSystemError};
+pub use crate::inode::{INode, INodeRef, INodeWeak};
+pub use crate::parser::{parse_file_system, ParseError};
+pub use crate::trace::Event;
+
+mod directory;
+mod file;
+mod file_system;
+mod generate;
+mod inode;
+mod parser;
+mod trace;
+
+/// Total space of the device, in bytes
+pub const DISK_SIZE: u64 = 70_000_000;
+/// Directories below this size are counted in part 1
+pub const SIZE_LIMIT: u64 = 100_000;
+/// Free space needed to run the update
+pub const REQUIRED_UPDATE_SIZE: u64 = 30_000_000;
+
+/// [`Solution`] for day 7
+pub struct NoSpaceLeft;
+
+impl Solution for NoSpaceLeft {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input = FileSystem;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        parse_file_system(input)
+    }
+
+    /// Returns the total size of the directories under `SIZE_LIMIT` bytes
+    fn part1(file_system: &Self::Input) -> Result<u64> {
+        Ok(sum_small_directories(file_system, &mut NoTrace))
+    }
+
+    /// Returns the size of the smallest directory that frees up enough space
+    /// to run the update, 0 when there is already enough free space
+    fn part2(file_system: &Self::Input) -> Result<u64> {
+        smallest_directory_to_delete(file_system, &mut NoTrace)
+    }
+
+    /// Space used on the device and the space the update needs freed
+    fn details(file_system: &Self::Input) -> Value {
+        let total_size = file_system.size();
+        let free_space = DISK_SIZE.saturating_sub(total_size);
+        let directories = file_system
+            .all_items()
+            .iter()
+            .filter(|item| item.borrow().is_directory())
+            .count();
+
+        json!({
+            "total_size": total_size,
+            "free_space": free_space,
+            "required_delete_size": REQUIRED_UPDATE_SIZE.saturating_sub(free_space),
+            "directories": directories,
+        })
+    }
+}
+
+/// Total size of the directories under `SIZE_LIMIT` bytes, sending each
+/// directory to `tracer`
+fn sum_small_directories(file_system: &FileSystem, tracer: &mut impl Tracer<Event>) -> u64 {
+    let mut total_size = 0;
+
+    for item in file_system.all_items() {
+        let item = item.borrow();
+        if !item.is_directory() {
+            continue;
+        }
+
+        let counted = item.size() < SIZE_LIMIT;
+        tracer.step(Event::Directory {
+            name: item.name().to_string(),
+            size: item.size(),
+            counted,
+        });
+        if counted {
+            total_size += item.size();
+        }
+    }
+
+    total_size
+}
+
+/// Size of the smallest directory that frees up enough space to run the
+/// update, sending each directory to `tracer`
+fn smallest_directory_to_delete(
+    file_system: &FileSystem,
+    tracer: &mut impl Tracer<Event>,
+) -> Result<u64> {
+    let total_size = file_system.size();
+    let free_space = DISK_SIZE.checked_sub(total_size).ok_or_else(|| {
+        anyhow!("The file system uses {total_size} bytes, more than the disk size {DISK_SIZE}")
+    })?;
+
+    // Nothing to delete when the update already fits
+    let Some(required_delete_size) = REQUIRED_UPDATE_SIZE.checked_sub(free_space) else {
+        return Ok(0);
+    };
+
+    let smallest_sufficient_directory = find_smallest_sufficient_directory_traced(
+        &file_system.all_items(),
+        required_delete_size,
+        tracer,
+    )
+    .ok_or_else(|| anyhow!("No directory frees up enough space"))?;
+
+    let size = smallest_sufficient_directory.borrow().size();
+    Ok(size)
+}
+
+/// Finds the smallest directory in `items` of at least `required_space` bytes
+pub fn find_smallest_sufficient_directory(
+    items: &[INodeRef],
+    required_space: u64,
+) -> Option<INodeRef> {
+    find_smallest_sufficient_directory_traced(items, required_space, &mut NoTrace)
+}
+
+/// [`find_smallest_sufficient_directory`], sending each directory to `tracer`
+fn find_smallest_sufficient_directory_traced(
+    items: &[INodeRef],
+    required_space: u64,
+    tracer: &mut impl Tracer<Event>,
+) -> Option<INodeRef> {
+    let mut smallest_sufficient_directory = None;
+    let mut smallest_sufficient_directory_size = u64::MAX;
+
+    for item in items {
+        let borrowed_item = item.try_borrow().expect("Dead item reference");
+        if !borrowed_item.is_directory() {
+            continue;
+        }
+
+        let counted = borrowed_item.size() >= required_space;
+        tracer.step(Event::Directory {
+            name: borrowed_item.name().to_string(),
+            size: borrowed_item.size(),
+            counted,
+        });
+        if counted && borrowed_item.size() < smallest_sufficient_directory_size {
+            smallest_sufficient_directory = Some(Rc::clone(item));
+            smallest_sufficient_directory_size = borrowed_item.size();
+        }
+    }
+
+    smallest_sufficient_directory
+}
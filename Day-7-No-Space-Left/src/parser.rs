@@ -4,8 +4,14 @@ use crate::{
     directory::Directory,
     file::File,
     file_system::{DirectoryChange, FileSystem},
+    trace::Event,
 };
 use anyhow::Result;
+use aoc_common::{
+    parse,
+    trace::{NoTrace, Tracer},
+    Diagnostic,
+};
 use thiserror::Error;
 
 /// Parsed action from a line of input
@@ -39,29 +45,41 @@ enum FileSystemCommand {
     ListDirectory,
 }
 
+/// Error produced while parsing the terminal output
 #[derive(Error, Debug)]
 pub enum ParseError {
+    /// Line is neither a command nor a directory item
     #[error("Invalid input: {0}")]
-    InvalidInput(String),
+    Input(String),
 
+    /// Command other than `cd` or `ls`
     #[error("Invalid command: {0}")]
-    InvalidCommand(String),
+    Command(String),
 
+    /// `cd` without a target directory
     #[error("Invalid directory change: {0}")]
-    InvalidDirectoryChange(String),
+    DirectoryChange(String),
+
+    /// File size that is not a number
+    #[error("Invalid file size: {0}")]
+    Size(String),
 }
 
 impl ParsedAction {
-    fn parse(line: &str) -> Result<Self> {
+    fn parse(line: &str) -> Result<Self, Diagnostic> {
         let line_parts = line.split_whitespace().collect::<Vec<&str>>();
 
         if line_parts.len() <= 1 {
-            return Err(ParseError::InvalidInput(line.to_string()).into());
+            return Err(Diagnostic::new(
+                ParseError::Input(line.to_string()).to_string(),
+                line,
+                line,
+            ));
         }
 
         match line_parts[0] {
             "$" => {
-                let command = FileSystemCommand::parse(&line_parts[1..])?;
+                let command = FileSystemCommand::parse(line, &line_parts[1..])?;
                 Ok(Self::Command(command))
             }
             _ => {
@@ -70,21 +88,49 @@ impl ParsedAction {
             }
         }
     }
+
+    /// Trace event of the action found on line `line`
+    fn event(&self, line: usize) -> Event {
+        match self {
+            Self::Command(FileSystemCommand::ChangeDirectory(change)) => Event::ChangeDirectory {
+                line,
+                target: match change {
+                    DirectoryChange::Root => "/".to_string(),
+                    DirectoryChange::Parent => "..".to_string(),
+                    DirectoryChange::Relative(name) => name.clone(),
+                },
+            },
+            Self::Command(FileSystemCommand::ListDirectory) => Event::List { line },
+            Self::DirectoryContent(DirectoryContent::File(file)) => Event::ListedFile {
+                line,
+                name: file.name.clone(),
+                size: u64::from(file.size),
+            },
+            Self::DirectoryContent(DirectoryContent::Directory(directory)) => {
+                Event::ListedDirectory {
+                    line,
+                    name: directory.name.clone(),
+                }
+            }
+        }
+    }
 }
 
 impl FileSystemCommand {
-    pub fn parse(command_parts: &[&str]) -> Result<Self> {
-        if command_parts.len() == 0 {
-            return Err(ParseError::InvalidInput(command_parts.join(" ")).into());
+    /// Parses the words of a command `line` after the `$` prompt
+    pub fn parse(line: &str, command_parts: &[&str]) -> Result<Self, Diagnostic> {
+        if command_parts.is_empty() {
+            return Err(Diagnostic::at_end(
+                ParseError::Input(line.to_string()).to_string(),
+                line,
+            ));
         }
 
         match command_parts[0] {
             "cd" => {
                 if command_parts.len() != 2 {
-                    return Err(ParseError::InvalidDirectoryChange(
-                        "Missing target directory".to_string(),
-                    )
-                    .into());
+                    let error = ParseError::DirectoryChange("Missing target directory".to_string());
+                    return Err(Diagnostic::at_end(error.to_string(), line));
                 }
 
                 let directory_change = match command_parts[1] {
@@ -96,16 +142,24 @@ impl FileSystemCommand {
                 Ok(Self::ChangeDirectory(directory_change))
             }
             "ls" => Ok(Self::ListDirectory),
-            _ => Err(ParseError::InvalidCommand(command_parts.join(" ")).into()),
+            _ => Err(Diagnostic::new(
+                ParseError::Command(command_parts.join(" ")).to_string(),
+                line,
+                command_parts[0],
+            )),
         }
     }
 }
 
 impl DirectoryContent {
-    fn parse(line: &str) -> Result<Self> {
+    fn parse(line: &str) -> Result<Self, Diagnostic> {
         let line_parts = line.split_whitespace().collect::<Vec<&str>>();
         if line_parts.len() != 2 {
-            return Err(ParseError::InvalidInput(line.to_string()).into());
+            return Err(Diagnostic::new(
+                ParseError::Input(line.to_string()).to_string(),
+                line,
+                line,
+            ));
         }
 
         match line_parts[0] {
@@ -116,9 +170,13 @@ impl DirectoryContent {
                 Ok(Self::Directory(directory_info))
             }
             _ => {
+                let size = line_parts[0].parse::<u32>().map_err(|_| {
+                    let error = ParseError::Size(line_parts[0].to_string());
+                    Diagnostic::new(error.to_string(), line, line_parts[0])
+                })?;
                 let file_info = FileInfo {
                     name: line_parts[1].to_string(),
-                    size: line_parts[0].parse::<u32>()?,
+                    size,
                 };
                 Ok(Self::File(file_info))
             }
@@ -126,12 +184,26 @@ impl DirectoryContent {
     }
 }
 
-pub fn parse_file_system(input: &str) -> Result<FileSystem> {
+/// Rebuilds the file system from the terminal output
+pub fn parse_file_system(input: &str) -> Result<FileSystem, Diagnostic> {
+    parse_file_system_traced(input, &mut NoTrace)
+}
+
+/// [`parse_file_system`], sending each command and listed item to `tracer`
+pub(crate) fn parse_file_system_traced(
+    input: &str,
+    tracer: &mut impl Tracer<Event>,
+) -> Result<FileSystem, Diagnostic> {
     let mut file_system = FileSystem::new();
 
-    for line in input.lines() {
-        let parsed_action = ParsedAction::parse(line)?;
-        perform_fs_action(&mut file_system, &parsed_action)?;
+    for line in parse::lines(input) {
+        let parsed_action = ParsedAction::parse(line.text).map_err(|e| e.on_line(line.number))?;
+        tracer.step(parsed_action.event(line.number));
+        perform_fs_action(&mut file_system, &parsed_action).map_err(|e| {
+            // Points at the directory of a `cd` or the name of a listed item
+            let target = line.tokens().last().unwrap_or(line.text);
+            line.error(format!("{e:#}"), target)
+        })?;
     }
 
     Ok(file_system)
@@ -141,7 +213,7 @@ fn perform_fs_action(file_system: &mut FileSystem, action: &ParsedAction) -> Res
     match action {
         ParsedAction::Command(command) => match command {
             FileSystemCommand::ChangeDirectory(directory_change) => {
-                file_system.change_directory(&directory_change)?;
+                file_system.change_directory(directory_change)?;
             }
             FileSystemCommand::ListDirectory => {
                 // file_system.list_directory
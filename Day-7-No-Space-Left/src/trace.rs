@@ -0,0 +1,78 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    trace::{Trace, Tracer},
+    Diagnostic,
+};
+use serde::Serialize;
+
+use crate::{
+    parser::parse_file_system_traced, smallest_directory_to_delete, sum_small_directories,
+    NoSpaceLeft,
+};
+
+/// Step of replaying the terminal output or of measuring the directories
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// `cd` into `target`, which is `/`, `..` or a directory name
+    ChangeDirectory {
+        /// Line of the command, starting at 1
+        line: usize,
+        /// Directory changed into
+        target: String,
+    },
+    /// `ls` of the current directory
+    List {
+        /// Line of the command, starting at 1
+        line: usize,
+    },
+    /// File listed by `ls`
+    ListedFile {
+        /// Line of the item, starting at 1
+        line: usize,
+        /// Name of the file
+        name: String,
+        /// Size of the file in bytes
+        size: u64,
+    },
+    /// Directory listed by `ls`
+    ListedDirectory {
+        /// Line of the item, starting at 1
+        line: usize,
+        /// Name of the directory
+        name: String,
+    },
+    /// Directory measured while solving a part
+    Directory {
+        /// Name of the directory
+        name: String,
+        /// Size of the directory and everything in it, in bytes
+        size: u64,
+        /// Whether the directory is small enough to count in part 1, or big
+        /// enough to free the space of the update in part 2
+        counted: bool,
+    },
+}
+
+impl Trace for NoSpaceLeft {
+    type Event = Event;
+
+    fn parse_traced(
+        input: &str,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<Self::Input, Diagnostic> {
+        parse_file_system_traced(input, tracer)
+    }
+
+    fn solve_traced(
+        file_system: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(sum_small_directories(file_system, tracer).to_string()),
+            2 => Ok(smallest_directory_to_delete(file_system, tracer)?.to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_common::{trace::Trace, Solution};
+use day_7_no_space_left::{
+    find_smallest_sufficient_directory, parse_file_system, Event, INode, NoSpaceLeft,
+};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let file_system = NoSpaceLeft::parse(EXAMPLE).unwrap();
+    assert_eq!(NoSpaceLeft::part1(&file_system).unwrap(), 95437);
+    assert_eq!(NoSpaceLeft::part2(&file_system).unwrap(), 24933642);
+}
+
+#[test]
+fn computes_directory_sizes() {
+    let file_system = parse_file_system(EXAMPLE).unwrap();
+    assert_eq!(file_system.size(), 48381165);
+
+    let a = file_system.find_item("a").unwrap();
+    assert!(a.borrow().is_directory());
+    assert_eq!(a.borrow().size(), 94853);
+
+    let e = a.borrow().find_item("e").unwrap();
+    assert_eq!(e.borrow().size(), 584);
+
+    let d = file_system.find_item("d").unwrap();
+    assert_eq!(d.borrow().size(), 24933642);
+}
+
+#[test]
+fn lists_all_items() {
+    let file_system = parse_file_system(EXAMPLE).unwrap();
+    let all_items = file_system.all_items();
+
+    assert_eq!(all_items.len(), 13);
+    let directories = all_items
+        .iter()
+        .filter(|item| item.borrow().is_directory())
+        .count();
+    assert_eq!(directories, 3);
+}
+
+#[test]
+fn finds_smallest_sufficient_directory() {
+    let file_system = parse_file_system(EXAMPLE).unwrap();
+    let directory = find_smallest_sufficient_directory(&file_system.all_items(), 8381165).unwrap();
+    assert_eq!(directory.borrow().name(), "d");
+}
+
+#[test]
+fn handles_file_systems_of_any_size() {
+    // Plenty of free space already
+    let tiny = NoSpaceLeft::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+    assert_eq!(NoSpaceLeft::part2(&tiny).unwrap(), 0);
+
+    let full = NoSpaceLeft::parse("$ cd /\n$ ls\n40000000 a\n40000000 b\n").unwrap();
+    assert!(NoSpaceLeft::part2(&full).is_err());
+
+    // More than a u32 can count
+    let huge = NoSpaceLeft::parse("$ cd /\n$ ls\n4000000000 a\n4000000000 b\n").unwrap();
+    assert_eq!(huge.size(), 8_000_000_000);
+    assert!(NoSpaceLeft::part2(&huge).is_err());
+}
+
+#[test]
+fn reports_unknown_directory() {
+    let error = parse_file_system("$ cd /\n$ ls\ndir a\n$ cd b\n").unwrap_err();
+    assert_eq!((error.line, error.column), (4, 6));
+}
+
+#[test]
+fn reports_invalid_file_size() {
+    let error = parse_file_system("$ cd /\n$ ls\n12x b.txt\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.len), (3, 1, 3));
+}
+
+#[test]
+fn traces_traversal_and_directories() {
+    let mut steps = Vec::new();
+    let file_system = NoSpaceLeft::parse_traced(EXAMPLE, &mut steps).unwrap();
+    assert_eq!(
+        steps[..2],
+        [
+            Event::ChangeDirectory {
+                line: 1,
+                target: "/".to_string()
+            },
+            Event::List { line: 2 }
+        ]
+    );
+    assert_eq!(steps.len(), EXAMPLE.lines().count());
+
+    let mut steps = Vec::new();
+    let answer = NoSpaceLeft::solve_traced(&file_system, 1, &mut steps).unwrap();
+    assert_eq!(answer, "95437");
+
+    let mut counted: Vec<&str> = steps
+        .iter()
+        .filter_map(|step| match step {
+            Event::Directory {
+                name,
+                counted: true,
+                ..
+            } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    counted.sort_unstable();
+    assert_eq!(counted, ["a", "e"]);
+}
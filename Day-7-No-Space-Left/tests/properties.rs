@@ -0,0 +1,105 @@
+use aoc_common::Solution;
+use day_7_no_space_left::{INode, NoSpaceLeft, SIZE_LIMIT};
+use proptest::prelude::*;
+
+/// Directories as (parent seed, file sizes), the parent of directory `i` is
+/// `seed % i` so every directory hangs from an earlier one
+fn directories() -> impl Strategy<Value = Vec<(usize, Vec<u32>)>> {
+    prop::collection::vec(
+        (any::<usize>(), prop::collection::vec(1..200_000u32, 0..4)),
+        1..30,
+    )
+}
+
+fn parents(directories: &[(usize, Vec<u32>)]) -> Vec<Option<usize>> {
+    (0..directories.len())
+        .map(|i| (i > 0).then(|| directories[i].0 % i))
+        .collect()
+}
+
+fn transcript(directories: &[(usize, Vec<u32>)]) -> String {
+    let parents = parents(directories);
+
+    let mut transcript = String::from("$ cd /\n");
+    let visit = |directory: usize, transcript: &mut String| {
+        *transcript += "$ ls\n";
+        for child in (0..directories.len()).filter(|i| parents[*i] == Some(directory)) {
+            *transcript += &format!("dir d{child}\n");
+        }
+        for (i, size) in directories[directory].1.iter().enumerate() {
+            *transcript += &format!("{size} f{i}\n");
+        }
+    };
+    visit(0, &mut transcript);
+
+    // Every directory is reached from the root by its full path
+    for directory in 1..directories.len() {
+        let mut path = vec![directory];
+        while let Some(parent) = parents[*path.last().unwrap()].filter(|parent| *parent != 0) {
+            path.push(parent);
+        }
+        transcript += "$ cd /\n";
+        for step in path.iter().rev() {
+            transcript += &format!("$ cd d{step}\n");
+        }
+        visit(directory, &mut transcript);
+    }
+
+    transcript
+}
+
+/// Size of each directory computed from the model
+fn sizes(directories: &[(usize, Vec<u32>)]) -> Vec<u64> {
+    let parents = parents(directories);
+    let mut sizes = vec![0; directories.len()];
+    for (directory, (_, files)) in directories.iter().enumerate() {
+        let mut current = Some(directory);
+        while let Some(ancestor) = current {
+            sizes[ancestor] += files.iter().copied().map(u64::from).sum::<u64>();
+            current = parents[ancestor];
+        }
+    }
+    sizes
+}
+
+proptest! {
+    #[test]
+    fn directory_size_is_sum_of_files_below(directories in directories()) {
+        let file_system = NoSpaceLeft::parse(&transcript(&directories)).unwrap();
+        let expected = sizes(&directories);
+
+        prop_assert_eq!(file_system.size(), expected[0]);
+
+        let mut found: Vec<u64> = file_system
+            .all_items()
+            .iter()
+            .map(|item| item.borrow())
+            .filter(|item| item.is_directory())
+            .map(|item| {
+                let files: u64 = item
+                    .all_items()
+                    .iter()
+                    .map(|item| item.borrow())
+                    .filter(|item| !item.is_directory())
+                    .map(|item| item.size())
+                    .sum();
+                assert_eq!(item.size(), files);
+                item.size()
+            })
+            .collect();
+        let mut expected = expected[1..].to_vec();
+        found.sort_unstable();
+        expected.sort_unstable();
+        prop_assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn part1_sums_small_directories(directories in directories()) {
+        let file_system = NoSpaceLeft::parse(&transcript(&directories)).unwrap();
+        let expected: u64 = sizes(&directories)[1..]
+            .iter()
+            .filter(|size| **size < SIZE_LIMIT)
+            .sum();
+        prop_assert_eq!(NoSpaceLeft::part1(&file_system).unwrap(), expected);
+    }
+}
@@ -1,5 +1,6 @@
 use std::cmp;
 
+/// Grid of tree heights, indexed by row and then column
 #[derive(Debug)]
 pub struct Forest {
     trees: Vec<Vec<u8>>,
@@ -13,11 +14,26 @@ enum Direction {
     Down,
 }
 
+impl Default for Forest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Vec<Vec<u8>>> for Forest {
+    /// Forest with the given rows of tree heights
+    fn from(trees: Vec<Vec<u8>>) -> Self {
+        Self { trees }
+    }
+}
+
 impl Forest {
+    /// Creates an empty forest
     pub fn new() -> Self {
         Self { trees: Vec::new() }
     }
 
+    /// Adds a tree at the end of the last row
     pub fn add_tree(&mut self, tree_height: u8) {
         if self.trees.is_empty() {
             self.trees.push(vec![tree_height]);
@@ -27,20 +43,24 @@ impl Forest {
         }
     }
 
+    /// Starts a new row of trees
     pub fn add_row(&mut self) {
         self.trees.push(Vec::new());
     }
 
+    /// Number of trees in each row
     pub fn width(&self) -> usize {
         self.trees[0].len()
     }
 
+    /// Number of rows
     pub fn height(&self) -> usize {
         self.trees.len()
     }
 
+    /// Determines if the tree at (`x`, `y`) can be seen from outside the grid
     pub fn is_visible(&self, x: usize, y: usize) -> bool {
-        let directions = vec![
+        let directions = [
             Direction::Right,
             Direction::Left,
             Direction::Up,
@@ -87,8 +107,10 @@ impl Forest {
         result
     }
 
+    /// Product of the viewing distances from the tree at (`x`, `y`) in
+    /// every direction
     pub fn get_scenic_score(&self, x: usize, y: usize) -> u32 {
-        let directions = vec![
+        let directions = [
             Direction::Up,
             Direction::Right,
             Direction::Down,
@@ -107,26 +129,21 @@ impl Forest {
     }
 }
 
-fn count_visible_trees(trees: &Vec<u8>, tree: u8) -> u32 {
+/// Counts the trees seen from a tree of height `tree` looking along `trees`,
+/// up to and including the first one at least as tall
+fn count_visible_trees(trees: &[u8], tree: u8) -> u32 {
     let mut count = 0;
-    let mut max_height = 0;
 
     for t in trees {
-        if t < &max_height {
-            continue;
-        }
         count += 1;
-        if t > &max_height {
-            max_height = *t;
-        }
-        if t > &tree {
+        if t >= &tree {
             break;
         }
     }
     count
 }
 
-fn multiply_vec(vec: &Vec<u32>) -> u32 {
+fn multiply_vec(vec: &[u32]) -> u32 {
     if vec.is_empty() {
         return 0;
     }
@@ -0,0 +1,18 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::TreetopTreeHouse;
+
+impl Generator for TreetopTreeHouse {
+    /// Generates a square forest of `size` rows of `size` trees
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut forest = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            for _ in 0..size {
+                forest.push(char::from(b'0' + rng.below(10) as u8));
+            }
+            forest.push('\n');
+        }
+        forest
+    }
+}
@@ -0,0 +1,124 @@
+//! Day 8: Treetop Tree House
+//!
+//! Surveys a grid of tree heights to find the trees visible from outside the
+//! grid and the best spot for a tree house.
+
+#![warn(missing_docs)]
+
+use std::cmp::Reverse;
+
+use anyhow::Result;
+use aoc_common::{
+    parse,
+    top::TopKExt,
+    trace::{NoTrace, Tracer},
+    Diagnostic, Solution,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+pub use forest::Forest;
+pub use trace::Event;
+
+mod forest;
+mod generate;
+mod trace;
+
+/// [`Solution`] for day 8
+pub struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Forest;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        parse::grid(input, "tree height", char_to_int).map(Forest::from)
+    }
+
+    /// Counts the trees visible from outside the grid
+    fn part1(forest: &Self::Input) -> Result<u32> {
+        Ok(count_visible(forest, &mut NoTrace))
+    }
+
+    /// Returns the highest scenic score of any tree
+    fn part2(forest: &Self::Input) -> Result<u32> {
+        Ok(best_scenic_score(forest, &mut NoTrace))
+    }
+
+    /// Size of the forest and its three most scenic trees
+    fn details(forest: &Self::Input) -> Value {
+        json!({
+            "width": forest.width(),
+            "height": forest.height(),
+            "best_spots": best_scenic_spots(forest, 3),
+        })
+    }
+}
+
+/// A tree and its scenic score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ScenicSpot {
+    /// Column of the tree, counted from 0
+    pub x: usize,
+    /// Row of the tree, counted from 0
+    pub y: usize,
+    /// Scenic score of the tree
+    pub score: u32,
+}
+
+/// Returns the `count` trees with the highest scenic scores, best first.
+/// Trees with the same score are in reading order
+pub fn best_scenic_spots(forest: &Forest, count: usize) -> Vec<ScenicSpot> {
+    (0..forest.height())
+        .flat_map(|y| (0..forest.width()).map(move |x| (x, y)))
+        .map(|(x, y)| (forest.get_scenic_score(x, y), Reverse(y), Reverse(x)))
+        .top_k(count)
+        .into_iter()
+        .map(|(score, Reverse(y), Reverse(x))| ScenicSpot { x, y, score })
+        .collect()
+}
+
+/// Counts the trees visible from outside the grid, sending each tree to
+/// `tracer`
+fn count_visible(forest: &Forest, tracer: &mut impl Tracer<Event>) -> u32 {
+    let mut visible_trees = 0;
+
+    for y in 0..forest.height() {
+        for x in 0..forest.width() {
+            let visible = forest.is_visible(x, y);
+            tracer.step(Event::Visibility { x, y, visible });
+            if visible {
+                visible_trees += 1;
+            }
+        }
+    }
+
+    visible_trees
+}
+
+/// Highest scenic score of any tree, sending the score of each tree to
+/// `tracer`
+fn best_scenic_score(forest: &Forest, tracer: &mut impl Tracer<Event>) -> u32 {
+    let mut max_score = 0;
+
+    for y in 0..forest.height() {
+        for x in 0..forest.width() {
+            let score = forest.get_scenic_score(x, y);
+            tracer.step(Event::Score { x, y, score });
+            if score > max_score {
+                max_score = score;
+            }
+        }
+    }
+
+    max_score
+}
+
+fn char_to_int(c: char) -> Option<u8> {
+    c.to_digit(10).map(|digit| digit as u8)
+}
@@ -1,43 +1,34 @@
-use std::fs;
+use std::{path::Path, process::exit};
 
-use forest::Forest;
+use aoc_common::{input, report, Solution};
+use day_8_treetop_house::TreetopTreeHouse;
 
-mod forest;
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
 
 fn main() {
-    let input = fs::read_to_string("./src/input.txt").expect("Could not read input file");
-    let mut forest = Forest::new();
-
-    for line in input.lines() {
-        forest.add_row();
-        for c in line.chars() {
-            let tree = char_to_int(c);
-            forest.add_tree(tree);
+    let (args, input) = match input::from_args(Path::new(DEFAULT_INPUT)) {
+        Ok(read) => read,
+        Err(e) => {
+            eprintln!("{e:#}");
+            exit(1);
         }
-    }
-
-    let mut visible_trees = 0;
-    let height = forest.height();
-    let width = forest.width();
-
-    let mut max_score = 0;
-
-    for y in 0..height {
-        for x in 0..width {
-            if forest.is_visible(x, y) {
-                visible_trees += 1;
-            }
-            let score = forest.get_scenic_score(x, y);
-            if score > max_score {
-                max_score = score;
-            }
+    };
+    let forest = match TreetopTreeHouse::parse(&input) {
+        Ok(forest) => forest,
+        Err(e) => {
+            eprintln!("{e}");
+            exit(1);
         }
-    }
+    };
 
-    println!("Visible trees: {}", visible_trees);
-    println!("Max score: {}", max_score);
-}
+    if args.json {
+        report::print::<TreetopTreeHouse>(&forest);
+        return;
+    }
 
-fn char_to_int(c: char) -> u8 {
-    c.to_string().parse().expect("Could not parse char to int")
+    println!(
+        "Visible trees: {}",
+        TreetopTreeHouse::part1(&forest).unwrap()
+    );
+    println!("Max score: {}", TreetopTreeHouse::part2(&forest).unwrap());
 }
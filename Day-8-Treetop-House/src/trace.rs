@@ -0,0 +1,46 @@
+use anyhow::{anyhow, Result};
+use aoc_common::trace::{Trace, Tracer};
+use serde::Serialize;
+
+use crate::{best_scenic_score, count_visible, TreetopTreeHouse};
+
+/// Step of surveying the forest, trees are at column `x` and row `y`
+/// counted from 0 at the top left
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// Visibility of a tree from outside the grid
+    Visibility {
+        /// Column of the tree
+        x: usize,
+        /// Row of the tree
+        y: usize,
+        /// Whether the tree can be seen from any edge
+        visible: bool,
+    },
+    /// Scenic score of a tree
+    Score {
+        /// Column of the tree
+        x: usize,
+        /// Row of the tree
+        y: usize,
+        /// Product of the viewing distances in the four directions
+        score: u32,
+    },
+}
+
+impl Trace for TreetopTreeHouse {
+    type Event = Event;
+
+    fn solve_traced(
+        forest: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(count_visible(forest, tracer).to_string()),
+            2 => Ok(best_scenic_score(forest, tracer).to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_common::{trace::Trace, Solution};
+use day_8_treetop_house::{best_scenic_spots, Event, ScenicSpot, TreetopTreeHouse};
+
+const EXAMPLE: &str = include_str!("fixtures/example.txt");
+
+#[test]
+fn solves_example() {
+    let forest = TreetopTreeHouse::parse(EXAMPLE).unwrap();
+    assert_eq!(TreetopTreeHouse::part1(&forest).unwrap(), 21);
+    assert_eq!(TreetopTreeHouse::part2(&forest).unwrap(), 8);
+}
+
+#[test]
+fn parses_forest() {
+    let forest = TreetopTreeHouse::parse(EXAMPLE).unwrap();
+    assert_eq!(forest.width(), 5);
+    assert_eq!(forest.height(), 5);
+}
+
+#[test]
+fn checks_visibility() {
+    let forest = TreetopTreeHouse::parse(EXAMPLE).unwrap();
+
+    // Every tree on the edge is visible
+    assert!(forest.is_visible(0, 0));
+    assert!(forest.is_visible(4, 2));
+
+    assert!(forest.is_visible(1, 1));
+    assert!(forest.is_visible(2, 1));
+    assert!(!forest.is_visible(3, 1));
+    assert!(!forest.is_visible(2, 2));
+}
+
+#[test]
+fn scores_scenery() {
+    let forest = TreetopTreeHouse::parse(EXAMPLE).unwrap();
+    assert_eq!(forest.get_scenic_score(2, 1), 4);
+    assert_eq!(forest.get_scenic_score(2, 3), 8);
+    assert_eq!(forest.get_scenic_score(0, 0), 0);
+}
+
+#[test]
+fn finds_best_scenic_spots() {
+    let forest = TreetopTreeHouse::parse(EXAMPLE).unwrap();
+    let spots = best_scenic_spots(&forest, 2);
+    assert_eq!(
+        spots[0],
+        ScenicSpot {
+            x: 2,
+            y: 3,
+            score: 8
+        }
+    );
+    assert_eq!(
+        spots[1],
+        ScenicSpot {
+            x: 1,
+            y: 2,
+            score: 6
+        }
+    );
+
+    // Every tree on the edge scores 0, so ties keep reading order
+    let all = best_scenic_spots(&forest, 25);
+    assert_eq!(all.len(), 25);
+    assert_eq!(
+        all[24],
+        ScenicSpot {
+            x: 4,
+            y: 4,
+            score: 0
+        }
+    );
+}
+
+#[test]
+fn reports_invalid_height() {
+    let error = TreetopTreeHouse::parse("303\n3x3\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+#[test]
+fn reports_uneven_rows() {
+    let error = TreetopTreeHouse::parse("303\n33\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+}
+
+#[test]
+fn traces_every_tree() {
+    let forest = TreetopTreeHouse::parse(EXAMPLE).unwrap();
+    let mut steps = Vec::new();
+    let answer = TreetopTreeHouse::solve_traced(&forest, 2, &mut steps).unwrap();
+    assert_eq!(answer, "8");
+
+    assert_eq!(steps.len(), 25);
+    assert!(steps.contains(&Event::Score {
+        x: 2,
+        y: 3,
+        score: 8
+    }));
+}
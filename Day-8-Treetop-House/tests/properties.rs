@@ -0,0 +1,79 @@
+use aoc_common::Solution;
+use day_8_treetop_house::TreetopTreeHouse;
+use proptest::prelude::*;
+
+fn forest() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+        prop::collection::vec(prop::collection::vec(0..10u8, width), height)
+    })
+}
+
+fn render(trees: &[Vec<u8>]) -> String {
+    trees
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|tree| char::from(b'0' + tree))
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+/// Heights seen walking from (`row`, `column`) towards each edge
+fn lines_of_sight(trees: &[Vec<u8>], row: usize, column: usize) -> [Vec<u8>; 4] {
+    [
+        trees[row][..column].iter().rev().copied().collect(),
+        trees[row][column + 1..].to_vec(),
+        trees[..row].iter().rev().map(|line| line[column]).collect(),
+        trees[row + 1..].iter().map(|line| line[column]).collect(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn parses_every_tree(trees in forest()) {
+        let forest = TreetopTreeHouse::parse(&render(&trees)).unwrap();
+        prop_assert_eq!((forest.width(), forest.height()), (trees[0].len(), trees.len()));
+    }
+
+    #[test]
+    fn part1_counts_trees_visible_from_an_edge(trees in forest()) {
+        let mut visible = 0;
+        for row in 0..trees.len() {
+            for column in 0..trees[0].len() {
+                let height = trees[row][column];
+                if lines_of_sight(&trees, row, column)
+                    .iter()
+                    .any(|line| line.iter().all(|tree| *tree < height))
+                {
+                    visible += 1;
+                }
+            }
+        }
+
+        let forest = TreetopTreeHouse::parse(&render(&trees)).unwrap();
+        prop_assert_eq!(TreetopTreeHouse::part1(&forest).unwrap(), visible);
+    }
+
+    #[test]
+    fn part2_is_best_scenic_score(trees in forest()) {
+        let mut best = 0;
+        for row in 0..trees.len() {
+            for column in 0..trees[0].len() {
+                let height = trees[row][column];
+                let score: u32 = lines_of_sight(&trees, row, column)
+                    .iter()
+                    .map(|line| {
+                        let blocked = line.iter().position(|tree| *tree >= height);
+                        blocked.map_or(line.len(), |i| i + 1) as u32
+                    })
+                    .product();
+                best = best.max(score);
+            }
+        }
+
+        let forest = TreetopTreeHouse::parse(&render(&trees)).unwrap();
+        prop_assert_eq!(TreetopTreeHouse::part2(&forest).unwrap(), best);
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::alloc::AllocationCount;
+use crate::days::Day;
+
+/// Timings of a day's parse and parts over every iteration
+#[derive(Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub phases: Vec<PhaseReport>,
+}
+
+/// Timings of one step of solving a puzzle
+#[derive(Serialize)]
+pub struct PhaseReport {
+    pub phase: &'static str,
+    pub iterations: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+    /// Allocations made by a single run of the phase
+    pub allocations: usize,
+    /// Bytes requested by a single run of the phase
+    pub allocated_bytes: usize,
+}
+
+impl PhaseReport {
+    fn new(phase: &'static str, mut timings: Vec<Duration>, allocations: AllocationCount) -> Self {
+        timings.sort();
+        let percentile = |p: usize| timings[(timings.len() * p).div_ceil(100).max(1) - 1];
+
+        Self {
+            phase,
+            iterations: timings.len(),
+            min_ns: timings[0].as_nanos(),
+            median_ns: percentile(50).as_nanos(),
+            p95_ns: percentile(95).as_nanos(),
+            allocations: allocations.allocations / timings.len(),
+            allocated_bytes: allocations.bytes / timings.len(),
+        }
+    }
+}
+
+/// Runs parse, part 1 and part 2 of `day` `iterations` times each
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayReport> {
+    let iterations = iterations.max(1);
+    let puzzle = day.puzzle;
+
+    // Warm up, which also surfaces any error before timing
+    let parsed = puzzle.parse(input)?;
+    puzzle.solve(parsed.as_ref(), 1)?;
+    puzzle.solve(parsed.as_ref(), 2)?;
+
+    let mut timings = Vec::with_capacity(iterations);
+    let allocations = AllocationCount::now();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = puzzle.parse(input)?;
+        timings.push(start.elapsed());
+        drop(parsed);
+    }
+    let mut phases = vec![PhaseReport::new(
+        "parse",
+        timings,
+        AllocationCount::now().since(allocations),
+    )];
+
+    for (phase, part) in [("part1", 1), ("part2", 2)] {
+        let mut timings = Vec::with_capacity(iterations);
+        let allocations = AllocationCount::now();
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let answer = puzzle.solve(parsed.as_ref(), part)?;
+            timings.push(start.elapsed());
+            drop(answer);
+        }
+        phases.push(PhaseReport::new(
+            phase,
+            timings,
+            AllocationCount::now().since(allocations),
+        ));
+    }
+
+    Ok(DayReport {
+        day: puzzle.day(),
+        title: puzzle.title(),
+        phases,
+    })
+}
+
+/// Prints a report as a human readable table
+pub fn print_report(report: &DayReport) {
+    println!("Day {}: {}", report.day, report.title);
+    for phase in &report.phases {
+        println!(
+            "  {:<6} min {:>10?}  median {:>10?}  p95 {:>10?}  {:>8} allocs {:>12} bytes",
+            phase.phase,
+            Duration::from_nanos(phase.min_ns as u64),
+            Duration::from_nanos(phase.median_ns as u64),
+            Duration::from_nanos(phase.p95_ns as u64),
+            phase.allocations,
+            phase.allocated_bytes,
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use aoc_common::{
+    generate::{Generator, Rng},
+    input::{read_input, INPUT_ENV},
+    trace::{record, Recorded},
+    Puzzle, Solver,
+};
+
+use crate::store::InputStore;
+
+/// A puzzle crate the runner can dispatch to
+pub struct Day {
+    /// Directory of the day's crate, relative to the workspace root
+    pub directory: &'static str,
+    pub puzzle: &'static dyn Puzzle,
+    /// Generates a random input of the given size, see [`Generator`]
+    pub generate: fn(usize, &mut Rng) -> String,
+    /// Solves a part writing each step as JSON lines, see [`record`]
+    pub trace: fn(&str, u8, &mut dyn Write) -> Result<Recorded>,
+}
+
+impl Day {
+    /// Input checked into the day's crate
+    pub fn default_input(&self) -> PathBuf {
+        workspace_root()
+            .join(self.directory)
+            .join("src")
+            .join("input.txt")
+    }
+
+    /// Reads the input from `path` or [`INPUT_ENV`] like [`read_input`], and
+    /// otherwise from [`Day::read_default_input`]
+    pub fn read_input(&self, path: Option<&Path>) -> Result<String> {
+        let from_env = env::var_os(INPUT_ENV).is_some_and(|path| !path.is_empty());
+        if path.is_some() || from_env {
+            return read_input(path, &self.default_input());
+        }
+
+        self.read_default_input()
+    }
+
+    /// Reads the input checked into the day's crate, or gets it from the
+    /// [`InputStore`] when that file is missing or still an empty placeholder
+    pub fn read_default_input(&self) -> Result<String> {
+        let path = self.default_input();
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if path.exists() => {
+                return Err(e)
+                    .with_context(|| format!("Could not read input file {}", path.display()))
+            }
+            Err(_) => String::new(),
+        };
+        if !input.is_empty() {
+            return Ok(input);
+        }
+
+        InputStore::from_env(&workspace_root().join(".inputs")).get(self.puzzle.day())
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        directory: "Day-1-Calorie-Counting",
+        puzzle: &Solver::<day_1_calorie_counting::CalorieCounting>::new(),
+        generate: day_1_calorie_counting::CalorieCounting::generate,
+        trace: record::<day_1_calorie_counting::CalorieCounting>,
+    },
+    Day {
+        directory: "Day-2-Rock-Paper-Scissors",
+        puzzle: &Solver::<day_2_rock_paper_scissors::RockPaperScissors>::new(),
+        generate: day_2_rock_paper_scissors::RockPaperScissors::generate,
+        trace: record::<day_2_rock_paper_scissors::RockPaperScissors>,
+    },
+    Day {
+        directory: "Day-3-Rucksack-Reorganization",
+        puzzle: &Solver::<day_3_rucksack_reorganization::RucksackReorganization>::new(),
+        generate: day_3_rucksack_reorganization::RucksackReorganization::generate,
+        trace: record::<day_3_rucksack_reorganization::RucksackReorganization>,
+    },
+    Day {
+        directory: "Day-4-Camp-Cleanup",
+        puzzle: &Solver::<day_4_camp_cleanup::CampCleanup>::new(),
+        generate: day_4_camp_cleanup::CampCleanup::generate,
+        trace: record::<day_4_camp_cleanup::CampCleanup>,
+    },
+    Day {
+        directory: "Day-5-Supply-Stacks",
+        puzzle: &Solver::<day_5_supply_stacks::SupplyStacks>::new(),
+        generate: day_5_supply_stacks::SupplyStacks::generate,
+        trace: record::<day_5_supply_stacks::SupplyStacks>,
+    },
+    Day {
+        directory: "Day-6-Tuning-Trouble",
+        puzzle: &Solver::<day_6_tuning_trouble::TuningTrouble>::new(),
+        generate: day_6_tuning_trouble::TuningTrouble::generate,
+        trace: record::<day_6_tuning_trouble::TuningTrouble>,
+    },
+    Day {
+        directory: "Day-7-No-Space-Left",
+        puzzle: &Solver::<day_7_no_space_left::NoSpaceLeft>::new(),
+        generate: day_7_no_space_left::NoSpaceLeft::generate,
+        trace: record::<day_7_no_space_left::NoSpaceLeft>,
+    },
+    Day {
+        directory: "Day-8-Treetop-House",
+        puzzle: &Solver::<day_8_treetop_house::TreetopTreeHouse>::new(),
+        generate: day_8_treetop_house::TreetopTreeHouse::generate,
+        trace: record::<day_8_treetop_house::TreetopTreeHouse>,
+    },
+];
+
+/// Root of the workspace, where the day crates live
+pub fn workspace_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.puzzle.day() == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Days with a generator, new days generate empty inputs until solved
+    fn generated_days() -> impl Iterator<Item = &'static Day> {
+        DAYS.iter()
+            .filter(|day| !(day.generate)(1, &mut Rng::new(0)).is_empty())
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        for day in generated_days() {
+            for (size, seed) in [(0, 0), (1, 1), (20, 2), (200, 3)] {
+                let input = (day.generate)(size, &mut Rng::new(seed));
+                let parsed = day.puzzle.parse(&input).unwrap_or_else(|e| {
+                    panic!("Day {} size {size}: {e:#}", day.puzzle.day());
+                });
+                for part in [1, 2] {
+                    if let Err(e) = day.puzzle.solve(parsed.as_ref(), part) {
+                        panic!("Day {} size {size} part {part}: {e:#}", day.puzzle.day());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        for day in generated_days() {
+            let generate = |seed| (day.generate)(20, &mut Rng::new(seed));
+            assert_eq!(generate(7), generate(7), "Day {}", day.puzzle.day());
+            assert_ne!(generate(7), generate(8), "Day {}", day.puzzle.day());
+        }
+    }
+
+    #[test]
+    fn size_grows_the_input() {
+        for day in generated_days() {
+            let generate = |size| (day.generate)(size, &mut Rng::new(0));
+            assert!(
+                generate(1).len() < generate(50).len(),
+                "Day {}",
+                day.puzzle.day()
+            );
+        }
+    }
+}
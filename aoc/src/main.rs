@@ -0,0 +1,322 @@
+use std::{fs::File, io, path::PathBuf, process::exit, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::generate::Rng;
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::alloc::CountingAllocator;
+
+mod alloc;
+mod bench;
+mod days;
+mod scaffold;
+mod server;
+mod store;
+mod verify;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs the Advent of Code 2022 solutions
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a day's puzzle
+    Run {
+        /// Day to solve
+        day: u8,
+
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input, `-` reads it from stdin. Defaults to the path in
+        /// `AOC_INPUT` and then to the day's `src/input.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+
+    /// Times parsing and solving each day
+    Bench {
+        /// Only benchmark this day
+        day: Option<u8>,
+
+        /// Times each step is repeated
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+
+        /// Puzzle input when benchmarking a single day, see `run`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+
+    /// Downloads a day's input into the input cache, unless already cached
+    Fetch {
+        /// Day to fetch
+        day: u8,
+    },
+
+    /// Prints a random input for a day, for stress testing
+    Generate {
+        /// Day to generate an input for
+        day: u8,
+
+        /// Number of records (elves, rounds, directories, ...) in the input
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+
+        /// Seed of the generator, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+
+    /// Solves one part of a day, writing every step of the solver as a line
+    /// of JSON
+    Trace {
+        /// Day to trace
+        day: u8,
+
+        /// Part to trace
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Puzzle input, see `run`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// File the steps are written to, instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Creates the crate for a new day and registers it in the workspace
+    New {
+        /// Number of the day
+        day: u8,
+
+        /// Puzzle title, e.g. `Rope Bridge`
+        #[arg(required = true, num_args = 1..)]
+        title: Vec<String>,
+    },
+
+    /// Re-runs a day whenever its input or source changes
+    Watch {
+        /// Day to watch
+        day: u8,
+
+        /// Alternate input solved and watched next to the day's own
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Milliseconds between checks for changes
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+
+        /// Answers of the run before a rebuild, passed on restart
+        #[arg(long, hide = true)]
+        previous: Option<String>,
+    },
+
+    /// Serves the solvers over HTTP, `POST /day/{n}/part/{p}` with the puzzle
+    /// input as the body answers with the JSON report of that part
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8022")]
+        address: String,
+    },
+
+    /// Checks every solver against the stored answers
+    Verify {
+        /// Only check this day
+        day: Option<u8>,
+
+        /// Answers file, defaults to `answers.json` in the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+}
+
+/// How results are printed
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Bench {
+            day,
+            iterations,
+            input,
+            format,
+        } => bench(day, iterations, input, format),
+        Command::Fetch { day } => fetch(day),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Trace {
+            day,
+            part,
+            input,
+            output,
+        } => trace(day, part, input, output),
+        Command::New { day, title } => new(day, &title.join(" ")),
+        Command::Watch {
+            day,
+            input,
+            interval,
+            previous,
+        } => watch(day, input, interval, previous),
+        Command::Serve { address } => server::serve(&address),
+        Command::Verify { day, answers } => {
+            let path = answers.unwrap_or_else(|| days::workspace_root().join("answers.json"));
+            verify::load(&path).and_then(|answers| verify::verify(&answers, day))
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {e:#}");
+        exit(1);
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<PathBuf>, format: Format) -> Result<()> {
+    let day = days::find(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?;
+
+    let input = day.read_input(input.as_deref())?;
+
+    let parsed = day.puzzle.parse(&input)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if format == Format::Json {
+        let report = day.puzzle.report(parsed.as_ref(), &parts)?;
+        println!("{report:#}");
+        return Ok(());
+    }
+
+    for part in parts {
+        println!("Part {part}: {}", day.puzzle.solve(parsed.as_ref(), part)?);
+    }
+
+    Ok(())
+}
+
+fn bench(day: Option<u8>, iterations: usize, input: Option<PathBuf>, format: Format) -> Result<()> {
+    let selected = match day {
+        Some(day) => vec![days::find(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?],
+        None => days::DAYS.iter().collect(),
+    };
+    let single_day = selected.len() == 1;
+
+    let mut reports = Vec::new();
+    for day in selected {
+        // `AOC_INPUT` can only stand for one day, so it is ignored when
+        // benchmarking all of them
+        let input = if single_day {
+            day.read_input(input.as_deref())?
+        } else {
+            day.read_default_input()?
+        };
+
+        let report = bench::bench_day(day, &input, iterations)
+            .with_context(|| format!("Day {} failed", day.puzzle.day()))?;
+
+        if format == Format::Text {
+            bench::print_report(&report);
+        }
+        reports.push(report);
+    }
+
+    if format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    }
+
+    Ok(())
+}
+
+fn generate(day: u8, size: usize, seed: u64) -> Result<()> {
+    let day = days::find(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?;
+    print!("{}", (day.generate)(size, &mut Rng::new(seed)));
+    Ok(())
+}
+
+fn trace(day: u8, part: u8, input: Option<PathBuf>, output: Option<PathBuf>) -> Result<()> {
+    let day = days::find(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?;
+    let input = day.read_input(input.as_deref())?;
+
+    let Some(path) = output else {
+        // The steps take stdout, so the answer goes to stderr
+        let recorded = (day.trace)(&input, part, &mut io::stdout().lock())?;
+        eprintln!("Part {part}: {}", recorded.answer);
+        return Ok(());
+    };
+
+    let mut file =
+        File::create(&path).with_context(|| format!("Could not create {}", path.display()))?;
+    let recorded = (day.trace)(&input, part, &mut file)?;
+    println!("Part {part}: {}", recorded.answer);
+    println!("{} steps written to {}", recorded.steps, path.display());
+    Ok(())
+}
+
+fn new(day: u8, title: &str) -> Result<()> {
+    if days::find(day).is_some() {
+        bail!("Day {day} already exists");
+    }
+
+    let names = scaffold::DayNames::new(day, title)?;
+    scaffold::scaffold(days::workspace_root(), &names)?;
+
+    println!("Created {}", names.directory);
+    println!("Paste your input into src/input.txt and the worked example into tests/fixtures/example.txt");
+    Ok(())
+}
+
+fn fetch(day: u8) -> Result<()> {
+    let day = days::find(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?;
+
+    let store = store::InputStore::from_env(&days::workspace_root().join(".inputs"));
+    store.get(day.puzzle.day())?;
+
+    println!("{}", store.path(day.puzzle.day()).display());
+    Ok(())
+}
+
+fn watch(day: u8, input: Option<PathBuf>, interval: u64, previous: Option<String>) -> Result<()> {
+    let day = days::find(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?;
+    let previous = previous
+        .map(|previous| serde_json::from_str(&previous))
+        .transpose()
+        .context("Invalid previous answers")?;
+
+    watch::watch(
+        day,
+        input.as_deref(),
+        Duration::from_millis(interval),
+        previous,
+    )
+}
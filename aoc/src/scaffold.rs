@@ -0,0 +1,249 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+/// Names a new day goes by, derived from its number and title
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayNames {
+    pub day: u8,
+    pub title: String,
+    /// Crate directory in the `Day-N-Title` style
+    pub directory: String,
+    /// Cargo package name
+    pub package: String,
+    /// Name the crate is imported by
+    pub crate_name: String,
+    /// Type implementing `Solution`
+    pub name: String,
+}
+
+impl DayNames {
+    pub fn new(day: u8, title: &str) -> Result<Self> {
+        if !(1..=25).contains(&day) {
+            bail!("Day {day} is not between 1 and 25");
+        }
+
+        let words: Vec<String> = title
+            .split_whitespace()
+            .map(|word| word.chars().filter(char::is_ascii_alphanumeric).collect())
+            .filter(|word: &String| !word.is_empty())
+            .map(|word| capitalize(&word))
+            .collect();
+        if words.is_empty() || words[0].starts_with(|c: char| c.is_ascii_digit()) {
+            bail!("Title {title:?} must start with a word");
+        }
+
+        let directory = format!("Day-{day}-{}", words.join("-"));
+        let package = directory.to_lowercase();
+
+        Ok(Self {
+            day,
+            title: title.trim().to_string(),
+            crate_name: package.replace('-', "_"),
+            package,
+            directory,
+            name: words.concat(),
+        })
+    }
+
+    /// Fills the placeholders of a template
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{package}", &self.package)
+            .replace("{crate_name}", &self.crate_name)
+            .replace("{name}", &self.name)
+            .replace("{day}", &self.day.to_string())
+            .replace("{title_literal}", &format!("{:?}", self.title))
+            .replace("{title}", &self.title)
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Files of a new day, relative to its crate directory
+const FILES: [(&str, &str); 8] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.template")),
+    (
+        "src/generate.rs",
+        include_str!("../templates/generate.rs.template"),
+    ),
+    ("src/main.rs", include_str!("../templates/main.rs.template")),
+    (
+        "src/trace.rs",
+        include_str!("../templates/trace.rs.template"),
+    ),
+    ("src/input.txt", ""),
+    (
+        "tests/example.rs",
+        include_str!("../templates/example.rs.template"),
+    ),
+    ("tests/fixtures/example.txt", ""),
+];
+
+/// Creates the crate of a new day in the workspace at `root` and registers it
+/// as a workspace member and in the runner. Returns the crate directory
+pub fn scaffold(root: &Path, names: &DayNames) -> Result<PathBuf> {
+    let directory = root.join(&names.directory);
+    if directory.exists() {
+        bail!("{} already exists", directory.display());
+    }
+
+    let manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("days.rs");
+
+    let prefix = format!("\"Day-{}-", names.day);
+    let workspace = read(&manifest)?;
+    if workspace.contains(&prefix) {
+        bail!("The workspace already has a crate for day {}", names.day);
+    }
+
+    // Every edit is prepared before writing anything, so a failure leaves the
+    // workspace untouched
+    let member = format!("    \"{}\",\n", names.directory);
+    let workspace = insert_before_closing(&workspace, "members = [", "]", &member)?;
+
+    let dependency = format!(
+        "{} = {{ path = \"../{}\" }}\n",
+        names.package, names.directory
+    );
+    let runner = read(&runner_manifest)?;
+    let runner = match runner.rfind("\nday-") {
+        Some(last) => {
+            let end = runner[last + 1..]
+                .find('\n')
+                .map_or(runner.len(), |end| last + 1 + end + 1);
+            format!("{}{dependency}{}", &runner[..end], &runner[end..])
+        }
+        None => format!("{}\n{dependency}", runner.trim_end()),
+    };
+
+    let entry = format!(
+        "    Day {{\n        directory: \"{0}\",\n        puzzle: &Solver::<{1}::{2}>::new(),\n        generate: {1}::{2}::generate,\n        trace: record::<{1}::{2}>,\n    }},\n",
+        names.directory, names.crate_name, names.name
+    );
+    let days = insert_before_closing(&read(&registry)?, "pub static DAYS", "];", &entry)?;
+
+    for (file, template) in FILES {
+        let path = directory.join(file);
+        fs::create_dir_all(path.parent().expect("Files are inside the crate"))?;
+        fs::write(&path, names.render(template))
+            .with_context(|| format!("Could not write {}", path.display()))?;
+    }
+
+    fs::write(&manifest, workspace)?;
+    fs::write(&runner_manifest, runner)?;
+    fs::write(&registry, days)?;
+
+    Ok(directory)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))
+}
+
+/// Inserts `text` at the start of the first line that begins with `closing`
+/// after `start`
+fn insert_before_closing(source: &str, start: &str, closing: &str, text: &str) -> Result<String> {
+    let start_index = source
+        .find(start)
+        .with_context(|| format!("Could not find {start:?}"))?;
+    let closing_index = source[start_index..]
+        .find(&format!("\n{closing}"))
+        .map(|index| start_index + index + 1)
+        .with_context(|| format!("Could not find the end of {start:?}"))?;
+
+    Ok(format!(
+        "{}{text}{}",
+        &source[..closing_index],
+        &source[closing_index..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn derives_names_from_title() {
+        let names = DayNames::new(11, "Monkey in the Middle").unwrap();
+        assert_eq!(names.directory, "Day-11-Monkey-In-The-Middle");
+        assert_eq!(names.package, "day-11-monkey-in-the-middle");
+        assert_eq!(names.crate_name, "day_11_monkey_in_the_middle");
+        assert_eq!(names.name, "MonkeyInTheMiddle");
+
+        assert_eq!(
+            DayNames::new(5, "Hill's 2nd climb").unwrap().name,
+            "Hills2ndClimb"
+        );
+        assert!(DayNames::new(26, "Too late").is_err());
+        assert!(DayNames::new(9, " - ").is_err());
+        assert!(DayNames::new(9, "1 Word").is_err());
+    }
+
+    #[test]
+    fn creates_and_registers_crate() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"Day-1-First\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday-1-first = { path = \"../Day-1-First\" }\n\n[dev-dependencies]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub static DAYS: &[Day] = &[\n    Day {},\n];\n\npub fn find() {}\n",
+        )
+        .unwrap();
+
+        let names = DayNames::new(9, "Rope Bridge").unwrap();
+        let directory = scaffold(&root, &names).unwrap();
+
+        let lib = fs::read_to_string(directory.join("src/lib.rs")).unwrap();
+        assert!(lib.starts_with("//! Day 9: Rope Bridge\n"));
+        assert!(lib.contains("pub struct RopeBridge;"));
+        assert!(lib.contains("const TITLE: &'static str = \"Rope Bridge\";"));
+        assert!(directory.join("src/input.txt").exists());
+        assert!(directory.join("tests/fixtures/example.txt").exists());
+
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"Day-1-First\",\n    \"Day-9-Rope-Bridge\",\n]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nday-1-first = { path = \"../Day-1-First\" }\nday-9-rope-bridge = { path = \"../Day-9-Rope-Bridge\" }\n\n[dev-dependencies]\n"
+        );
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains(
+            "        puzzle: &Solver::<day_9_rope_bridge::RopeBridge>::new(),\n        generate: day_9_rope_bridge::RopeBridge::generate,\n        trace: record::<day_9_rope_bridge::RopeBridge>,\n    },\n];\n"
+        ));
+
+        // A day can only be created once
+        assert!(scaffold(&root, &names).is_err());
+        fs::remove_dir_all(directory).unwrap();
+        assert!(scaffold(&root, &names).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    io::Read,
+    panic::{self, AssertUnwindSafe},
+    thread,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_common::Diagnostic;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::days::{self, Day};
+
+/// Largest puzzle input accepted, in bytes
+const MAX_INPUT: usize = 10 * 1024 * 1024;
+
+/// Serves `POST /day/{n}/part/{p}` on `address` until the process is stopped,
+/// handling each request on its own thread
+pub fn serve(address: &str) -> Result<()> {
+    let server =
+        Server::http(address).map_err(|e| anyhow!("Could not listen on {address}: {e}"))?;
+    println!("Listening on http://{}", server.server_addr());
+
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request));
+    }
+
+    Ok(())
+}
+
+fn respond(mut request: Request) {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT as u64 + 1)
+        .read_to_end(&mut body);
+
+    let (status, value) = match read {
+        Err(e) => error(400, "request", format!("Could not read the body: {e}")),
+        Ok(_) if body.len() > MAX_INPUT => error(
+            413,
+            "request",
+            format!("Input is larger than {MAX_INPUT} bytes"),
+        ),
+        Ok(_) => match String::from_utf8(body) {
+            Ok(input) => handle(request.method(), request.url(), &input),
+            Err(_) => error(400, "request", "Input is not valid UTF-8".to_string()),
+        },
+    };
+
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json").expect("Valid header");
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(e) = request.respond(response) {
+        eprintln!("Could not send a response: {e}");
+    }
+}
+
+/// Answers a request with its status code and JSON body: the report of the
+/// part, like `aoc run --format json`, or an error object
+pub fn handle(method: &Method, url: &str, input: &str) -> (u16, Value) {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let Some((day, part)) = route(path) else {
+        return error(404, "not_found", format!("No route for {path}"));
+    };
+    if *method != Method::Post {
+        return error(405, "method", format!("Use POST for {path}"));
+    }
+
+    let Some(day) = day.parse().ok().and_then(days::find) else {
+        return error(404, "not_found", format!("Day {day} is not solved yet"));
+    };
+    let part = match part.parse() {
+        Ok(part @ (1 | 2)) => part,
+        _ => {
+            return error(
+                404,
+                "not_found",
+                format!("Invalid part {part}, expected 1 or 2"),
+            )
+        }
+    };
+
+    solve(day, part, input)
+}
+
+/// Runs `respond`, answering with a JSON error when it panics so the client
+/// still gets a body
+fn catch_panic(respond: impl FnOnce() -> (u16, Value)) -> (u16, Value) {
+    panic::catch_unwind(AssertUnwindSafe(respond)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_string());
+        error(500, "panic", format!("Solver panicked: {message}"))
+    })
+}
+
+/// Day and part of a `/day/{n}/part/{p}` path
+fn route(path: &str) -> Option<(&str, &str)> {
+    let path = path.strip_suffix('/').unwrap_or(path);
+    match path.strip_prefix('/')?.split('/').collect::<Vec<_>>()[..] {
+        ["day", day, "part", part] => Some((day, part)),
+        _ => None,
+    }
+}
+
+/// Parses `input` and solves `part` of `day`, answering with a JSON error
+/// when the solver fails or panics
+fn solve(day: &Day, part: u8, input: &str) -> (u16, Value) {
+    catch_panic(|| {
+        let parsed = match day.puzzle.parse(input) {
+            Ok(parsed) => parsed,
+            Err(e) => return parse_error(&e),
+        };
+
+        match day.puzzle.report(parsed.as_ref(), &[part]) {
+            Ok(report) => (200, report),
+            Err(e) => error(422, "solve", format!("{e:#}")),
+        }
+    })
+}
+
+fn parse_error(e: &anyhow::Error) -> (u16, Value) {
+    match e.downcast_ref::<Diagnostic>() {
+        Some(diagnostic) => (
+            422,
+            json!({
+                "error": "parse",
+                "message": diagnostic.message,
+                "line": diagnostic.line,
+                "column": diagnostic.column,
+                "len": diagnostic.len,
+                "source_line": diagnostic.source_line,
+            }),
+        ),
+        None => error(422, "parse", format!("{e:#}")),
+    }
+}
+
+fn error(status: u16, kind: &str, message: String) -> (u16, Value) {
+    (status, json!({ "error": kind, "message": message }))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Solution, Solver};
+
+    use super::*;
+
+    #[test]
+    fn solves_a_part() {
+        let (status, body) = handle(
+            &Method::Post,
+            "/day/6/part/2",
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+        );
+        assert_eq!(status, 200);
+        assert_eq!(body["part2"], 19);
+        assert_eq!(body["day"], 6);
+        assert!(body.get("part1").is_none());
+    }
+
+    #[test]
+    fn reports_parse_errors_with_position() {
+        let (status, body) = handle(&Method::Post, "/day/2/part/1/", "A Y\nD X\n");
+        assert_eq!(status, 422);
+        assert_eq!(body["error"], "parse");
+        assert_eq!(body["message"], "Invalid shape \"D\"");
+        assert_eq!(
+            (body["line"].clone(), body["column"].clone()),
+            (json!(2), json!(1))
+        );
+        assert_eq!(body["source_line"], "D X");
+    }
+
+    #[test]
+    fn reports_solve_errors() {
+        let (status, body) = handle(&Method::Post, "/day/6/part/1", "aaaa");
+        assert_eq!(status, 422);
+        assert_eq!(body["error"], "solve");
+    }
+
+    /// Puzzle whose part 1 always panics
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 99;
+        const TITLE: &'static str = "Panicking";
+
+        type Input = String;
+
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<String, Diagnostic> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &String) -> Result<u32> {
+            panic!("No answer for {input:?}");
+        }
+
+        fn part2(_input: &String) -> Result<u32> {
+            Ok(0)
+        }
+    }
+
+    static PANICKING: Solver<Panicking> = Solver::new();
+
+    #[test]
+    fn reports_panics_as_json() {
+        let day = Day {
+            directory: "Day-99-Panicking",
+            puzzle: &PANICKING,
+            generate: |_, _| String::new(),
+            trace: |_, _, _| unreachable!("Not traced"),
+        };
+
+        let (status, body) = solve(&day, 1, "x");
+        assert_eq!(status, 500);
+        assert_eq!(body["error"], "panic");
+        assert_eq!(body["message"], "Solver panicked: No answer for \"x\"");
+
+        assert_eq!(solve(&day, 2, "x").0, 200);
+    }
+
+    #[test]
+    fn rejects_unknown_routes() {
+        assert_eq!(handle(&Method::Post, "/day/26/part/1", "").0, 404);
+        assert_eq!(handle(&Method::Post, "/day/1/part/3", "").0, 404);
+        assert_eq!(handle(&Method::Post, "/day/x/part/1", "").0, 404);
+        assert_eq!(handle(&Method::Post, "/days", "").0, 404);
+        assert_eq!(handle(&Method::Get, "/day/1/part/1", "").0, 405);
+    }
+
+    #[test]
+    fn serves_over_http() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/day/4/part/1", server.server_addr());
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                respond(request);
+            }
+        });
+
+        let mut response = ureq::post(&url).send("2-4,6-8\n2-8,3-7\n").unwrap();
+        assert_eq!(
+            response.headers().get("Content-Type").unwrap(),
+            "application/json"
+        );
+        let body: Value =
+            serde_json::from_str(&response.body_mut().read_to_string().unwrap()).unwrap();
+        assert_eq!(body["part1"], 1);
+    }
+}
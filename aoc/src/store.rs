@@ -0,0 +1,218 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use ureq::Agent;
+
+/// Puzzle year the inputs are fetched for
+const YEAR: u16 = 2022;
+
+/// Environment variable overriding the cache directory
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+/// Environment variable overriding the site inputs are fetched from
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable with the session cookie of a logged in user
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Puzzle inputs cached on disk, fetched from the site the first time a day
+/// is needed
+pub struct InputStore {
+    cache_dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl InputStore {
+    pub fn new(cache_dir: PathBuf, base_url: &str, session: Option<String>) -> Self {
+        Self {
+            cache_dir,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// Store configured from [`CACHE_DIR_ENV`], [`BASE_URL_ENV`] and
+    /// [`SESSION_ENV`], caching into `default_cache_dir` and fetching from
+    /// the real site unless overridden
+    pub fn from_env(default_cache_dir: &Path) -> Self {
+        let var = |name| env::var(name).ok().filter(|value| !value.trim().is_empty());
+
+        Self::new(
+            var(CACHE_DIR_ENV).map_or_else(|| default_cache_dir.to_path_buf(), PathBuf::from),
+            &var(BASE_URL_ENV).unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            var(SESSION_ENV).map(|session| session.trim().to_string()),
+        )
+    }
+
+    /// Where the input of `day` is cached
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(YEAR.to_string())
+            .join(format!("day-{day}.txt"))
+    }
+
+    /// Returns the input of `day`, only fetching it if it is not cached yet
+    pub fn get(&self, day: u8) -> Result<String> {
+        let path = self.path(day);
+        if path.exists() {
+            return fs::read_to_string(&path)
+                .with_context(|| format!("Could not read cached input {}", path.display()));
+        }
+
+        let input = self.fetch(day)?;
+
+        // Written next to the final path and renamed, so an interrupted write
+        // never leaves a truncated input in the cache
+        fs::create_dir_all(path.parent().expect("Cached inputs are in a directory"))?;
+        let partial = path.with_extension("partial");
+        fs::write(&partial, &input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .with_context(|| format!("Could not cache input in {}", path.display()))?;
+
+        Ok(input)
+    }
+
+    fn fetch(&self, day: u8) -> Result<String> {
+        let Some(session) = &self.session else {
+            bail!("Input for day {day} is not cached and {SESSION_ENV} is not set to fetch it");
+        };
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let agent: Agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(concat!(
+                "Advent_Of_Code_2022 input store/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+
+        let mut response = agent
+            .get(&url)
+            .header("Cookie", format!("session={session}"))
+            .call()
+            .with_context(|| format!("Could not fetch {url}"))?;
+
+        let status = response.status();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .with_context(|| format!("Could not read the response of {url}"))?;
+        if !status.is_success() {
+            // The site explains errors in a single line, anything longer is
+            // most likely a whole page
+            let body = body.trim();
+            if body.is_empty() || body.len() > 200 {
+                bail!("Fetching {url} failed with {status}");
+            }
+            bail!("Fetching {url} failed with {status}: {body}");
+        }
+
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// Path and cookie of each request the mock server received
+    type Requests = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Local server answering every request with `status` and `body`
+    fn mock_server(status: u16, body: &'static str) -> (String, Requests) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push((request.url().to_string(), cookie));
+                request
+                    .respond(Response::from_string(body).with_status_code(status))
+                    .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-store-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_once_and_caches() {
+        let (url, requests) = mock_server(200, "1000\n2000\n");
+        let dir = cache_dir("fetch");
+        let store = InputStore::new(dir.clone(), &format!("{url}/"), Some("secret".to_string()));
+
+        assert_eq!(store.get(1).unwrap(), "1000\n2000\n");
+        assert_eq!(store.get(1).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            fs::read_to_string(dir.join("2022/day-1.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [(
+                "/2022/day/1/input".to_string(),
+                "session=secret".to_string()
+            )]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reads_cache_without_session() {
+        let dir = cache_dir("cached");
+        let store = InputStore::new(dir.clone(), "http://127.0.0.1:9", None);
+
+        assert!(store.get(3).is_err());
+
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        fs::write(dir.join("2022/day-3.txt"), "vJrwpWtwJgWr\n").unwrap();
+        assert_eq!(store.get(3).unwrap(), "vJrwpWtwJgWr\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn does_not_cache_failures() {
+        let (url, requests) = mock_server(400, "Please log in");
+        let dir = cache_dir("failure");
+        let store = InputStore::new(dir.clone(), &url, Some("expired".to_string()));
+
+        let error = store.get(2).unwrap_err().to_string();
+        assert!(error.contains("400"), "{error}");
+        assert!(store.get(2).is_err());
+        assert!(!store.path(2).exists());
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+use crate::days::{self, workspace_root};
+
+/// Known answers for one input of a day
+#[derive(Deserialize)]
+pub struct ExpectedAnswers {
+    pub day: u8,
+    /// Input path, relative to the workspace root
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Reads the expected answers stored at `path`
+pub fn load(path: &Path) -> Result<Vec<ExpectedAnswers>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Could not read answers file {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid answers file {}", path.display()))
+}
+
+/// Solves every input in `answers` and prints whether each part matches,
+/// failing if any of them does not or if there is nothing to check
+pub fn verify(answers: &[ExpectedAnswers], day: Option<u8>) -> Result<()> {
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for expected in answers
+        .iter()
+        .filter(|a| day.is_none_or(|day| a.day == day))
+    {
+        for (part, answer) in [(1, &expected.part1), (2, &expected.part2)] {
+            let Some(answer) = answer else {
+                continue;
+            };
+
+            checked += 1;
+            let label = format!("Day {} part {part} ({})", expected.day, expected.input);
+            match solve(expected, part) {
+                Ok(actual) if actual == *answer => println!("{label}: ok"),
+                Ok(actual) => {
+                    failed += 1;
+                    println!("{label}: MISMATCH, expected {answer:?} but got {actual:?}");
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("{label}: ERROR, {e:#}");
+                }
+            }
+        }
+    }
+
+    println!("{checked} answers checked, {failed} failed");
+
+    if checked == 0 {
+        match day {
+            Some(day) => bail!("No answers recorded for day {day}"),
+            None => bail!("No answers recorded"),
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} of {checked} answers did not match");
+    }
+    Ok(())
+}
+
+fn solve(expected: &ExpectedAnswers, part: u8) -> Result<String> {
+    let day = days::find(expected.day)
+        .ok_or_else(|| anyhow!("Day {} is not solved yet", expected.day))?;
+
+    let path = workspace_root().join(&expected.input);
+    let input = fs::read_to_string(&path)
+        .with_context(|| format!("Could not read input file {}", path.display()))?;
+
+    let parsed = day.puzzle.parse(&input)?;
+    day.puzzle.solve(parsed.as_ref(), part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_when_nothing_is_checked() {
+        let answers = [ExpectedAnswers {
+            day: 1,
+            input: "Day-1-Calorie-Counting/tests/fixtures/example.txt".to_string(),
+            part1: Some("24000".to_string()),
+            part2: None,
+        }];
+
+        assert!(verify(&answers, Some(1)).is_ok());
+        assert!(verify(&answers, Some(42)).is_err());
+        assert!(verify(&[], None).is_err());
+    }
+}
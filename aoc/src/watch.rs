@@ -0,0 +1,264 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::days::{workspace_root, Day};
+
+/// Answer of each input and part, keyed by `<input> part <n>`. Errors are
+/// kept as answers so a fix shows up in the diff
+pub type Answers = BTreeMap<String, String>;
+
+/// Modification time of every watched file
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Re-runs `day` every time its input, the `alternate` input or the source of
+/// the day or of `aoc-common` changes, printing how the answers changed.
+/// `previous` are the answers of the run before the runner was rebuilt
+pub fn watch(
+    day: &Day,
+    alternate: Option<&Path>,
+    interval: Duration,
+    previous: Option<Answers>,
+) -> Result<()> {
+    let mut inputs = vec![day.default_input()];
+    inputs.extend(alternate.map(Path::to_path_buf));
+    let sources = [
+        workspace_root().join(day.directory).join("src"),
+        workspace_root().join("common").join("src"),
+    ];
+
+    // Resolved before any rebuild, which replaces the executable
+    let runner = env::current_exe()?;
+
+    let mut input_snapshot = snapshot(&inputs);
+    let mut source_snapshot = snapshot_sources(&sources);
+    let mut previous = previous;
+
+    println!(
+        "Watching day {} ({}), press Ctrl-C to stop",
+        day.puzzle.day(),
+        day.puzzle.title()
+    );
+    loop {
+        let started = Instant::now();
+        let answers = solve(day, alternate);
+        println!("[{:.2?}]", started.elapsed());
+        print!("{}", diff(previous.as_ref(), &answers));
+        previous = Some(answers);
+
+        loop {
+            thread::sleep(interval);
+
+            if snapshot_sources(&sources) != source_snapshot {
+                println!("Source changed, rebuilding");
+                rebuild(&runner, previous.as_ref().expect("Solved at least once"))?;
+                source_snapshot = snapshot_sources(&sources);
+            }
+
+            let current = snapshot(&inputs);
+            if current != input_snapshot {
+                input_snapshot = current;
+                println!("Input changed");
+                break;
+            }
+        }
+    }
+}
+
+fn solve(day: &Day, alternate: Option<&Path>) -> Answers {
+    let mut answers = Answers::new();
+
+    let mut inputs = vec![("input".to_string(), day.read_default_input())];
+    if let Some(path) = alternate {
+        let input = fs::read_to_string(path)
+            .with_context(|| format!("Could not read input file {}", path.display()));
+        inputs.push((path.display().to_string(), input));
+    }
+
+    for (label, input) in inputs {
+        let parsed = match input.and_then(|input| day.puzzle.parse(&input)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                // Diagnostics span several lines, only their first one is
+                // kept as the answer
+                println!("{label}: {e:#}");
+                for part in [1, 2] {
+                    answers.insert(format!("{label} part {part}"), error(&e));
+                }
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let answer = day
+                .puzzle
+                .solve(parsed.as_ref(), part)
+                .unwrap_or_else(|e| error(&e));
+            answers.insert(format!("{label} part {part}"), answer);
+        }
+    }
+
+    answers
+}
+
+fn error(error: &anyhow::Error) -> String {
+    let message = format!("{error:#}");
+    format!("error: {}", message.lines().next().unwrap_or_default())
+}
+
+/// Lists every answer, noting the ones that changed since `previous`
+pub fn diff(previous: Option<&Answers>, answers: &Answers) -> String {
+    let mut output = String::new();
+
+    for (key, answer) in answers {
+        let change = match previous.and_then(|previous| previous.get(key)) {
+            None => String::new(),
+            Some(old) if old == answer => " (unchanged)".to_string(),
+            Some(old) => format!(" (was {old})"),
+        };
+        output += &format!("  {key}: {answer}{change}\n");
+    }
+
+    output
+}
+
+/// Modification times of `paths` and of every file below them, missing paths
+/// are left out so creating or deleting them counts as a change
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = paths.to_vec();
+
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            snapshot.insert(path, modified);
+        }
+    }
+
+    snapshot
+}
+
+/// [`snapshot`] of the Rust files below `paths`, leaving out the inputs kept
+/// next to the sources such as `src/input.txt`
+fn snapshot_sources(paths: &[PathBuf]) -> Snapshot {
+    snapshot(paths)
+        .into_iter()
+        .filter(|(path, _)| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect()
+}
+
+/// Rebuilds the runner and replaces the current process with the new build,
+/// handing over the last answers so the diff carries on
+fn rebuild(runner: &Path, previous: &Answers) -> Result<()> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut build = Command::new(cargo);
+    build.args(["build", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+
+    let status = build
+        .current_dir(workspace_root())
+        .status()
+        .context("Could not run cargo")?;
+    if !status.success() {
+        println!("Build failed, waiting for the next change");
+        return Ok(());
+    }
+
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if let Some(index) = args.iter().position(|arg| arg == "--previous") {
+        args.drain(index..(index + 2).min(args.len()));
+    }
+    args.push("--previous".to_string());
+    args.push(serde_json::to_string(previous)?);
+
+    let mut runner = Command::new(runner);
+    runner.args(args);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let error = runner.exec();
+        bail!("Could not restart the runner: {error}");
+    }
+
+    #[cfg(not(unix))]
+    {
+        let status = runner.status().context("Could not restart the runner")?;
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_changed_answers() {
+        let answers = |pairs: &[(&str, &str)]| -> Answers {
+            pairs
+                .iter()
+                .map(|(key, answer)| (key.to_string(), answer.to_string()))
+                .collect()
+        };
+        let before = answers(&[("input part 1", "21"), ("input part 2", "4")]);
+        let after = answers(&[
+            ("input part 1", "21"),
+            ("input part 2", "8"),
+            ("small.txt part 1", "error: Row has no trees"),
+        ]);
+
+        assert_eq!(
+            diff(None, &before),
+            "  input part 1: 21\n  input part 2: 4\n"
+        );
+        assert_eq!(
+            diff(Some(&before), &after),
+            "  input part 1: 21 (unchanged)\n  input part 2: 8 (was 4)\n  small.txt part 1: error: Row has no trees\n"
+        );
+    }
+
+    #[test]
+    fn snapshot_sees_new_and_modified_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        let watched = [dir.clone()];
+
+        let empty = snapshot(&watched);
+        fs::write(dir.join("nested/lib.rs"), "// one").unwrap();
+        let created = snapshot(&watched);
+        assert_ne!(created, empty);
+        assert_eq!(created.len(), 1);
+
+        let file = fs::File::options()
+            .write(true)
+            .open(dir.join("nested/lib.rs"))
+            .unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        assert_ne!(snapshot(&watched), created);
+
+        // Inputs next to the sources don't count as sources
+        let sources = snapshot_sources(&watched);
+        fs::write(dir.join("nested/input.txt"), "1").unwrap();
+        assert_eq!(snapshot_sources(&watched), sources);
+        assert_ne!(snapshot(&watched), sources);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
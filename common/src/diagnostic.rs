@@ -0,0 +1,100 @@
+//! Parse errors pointing at the offending text of the input
+
+use std::{error::Error, fmt};
+
+/// Error found while parsing a puzzle input, with the position of the
+/// offending text so it can be shown with a caret under it
+///
+/// ```text
+/// Invalid shape "D"
+///  --> line 3, column 1
+///   |
+/// 3 | D Y
+///   | ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Description of the problem
+    pub message: String,
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column of the first offending character, starting at 1
+    pub column: usize,
+    /// Amount of offending characters
+    pub len: usize,
+    /// Text of the offending line
+    pub source_line: String,
+}
+
+impl Diagnostic {
+    /// Points at `snippet`, a slice of `line` such as the ones returned by
+    /// `split` or `split_whitespace`
+    ///
+    /// If `snippet` is not a slice of `line` its first occurrence is used, and
+    /// the whole line when it does not appear at all.
+    /// The diagnostic is on line 1 until [`Diagnostic::on_line`] is called.
+    pub fn new(message: impl Into<String>, line: &str, snippet: &str) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let snippet_start = snippet.as_ptr() as usize;
+
+        let offset = if snippet_start >= line_start
+            && snippet_start + snippet.len() <= line_start + line.len()
+        {
+            Some(snippet_start - line_start)
+        } else {
+            line.find(snippet)
+        };
+
+        match offset {
+            Some(offset) => Self::at_column(
+                message,
+                line,
+                line[..offset].chars().count(),
+                snippet.chars().count(),
+            ),
+            None => Self::at_column(message, line, 0, line.chars().count()),
+        }
+    }
+
+    /// Points at `len` characters of `line` starting at the character index
+    /// `column`, counted from 0
+    pub fn at_column(message: impl Into<String>, line: &str, column: usize, len: usize) -> Self {
+        Self {
+            message: message.into(),
+            line: 1,
+            column: column + 1,
+            len: len.max(1),
+            source_line: line.to_string(),
+        }
+    }
+
+    /// Points just past the end of `line`, for text that is missing
+    pub fn at_end(message: impl Into<String>, line: &str) -> Self {
+        Self::at_column(message, line, line.chars().count(), 1)
+    }
+
+    /// Sets the line of the input the diagnostic points at, starting at 1
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Error for Diagnostic {}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{gutter}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len)
+        )
+    }
+}
@@ -0,0 +1,64 @@
+//! Random puzzle inputs for stress testing the solvers
+
+use std::ops::RangeInclusive;
+
+use crate::Solution;
+
+/// A day that can generate valid inputs of any size
+pub trait Generator: Solution {
+    /// Generates an input with about `size` records, what a record is depends
+    /// on the day. The same `size` and seed always give the same input
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// Small deterministic random number generator (SplitMix64), so generated
+/// inputs can be reproduced from their seed on any platform
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random number
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound`, `bound` must not be 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Empty range");
+        // Multiply-shift keeps the bias negligible for the bounds used here
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Returns a number in `range`, which must not be empty
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        match (end - start).checked_add(1) {
+            Some(span) => start + ((self.next_u64() as u128 * span as u128) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Returns a random element of `items`, which must not be empty
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffles `items` in place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
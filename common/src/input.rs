@@ -0,0 +1,250 @@
+//! Locating and reading the puzzle input
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    ffi::OsString,
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+/// Environment variable with the input path, used when no path is given
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Input path that reads the puzzle input from stdin
+pub const STDIN: &str = "-";
+
+/// Reads the puzzle input from `path`, falling back to the path in
+/// [`INPUT_ENV`] and then to `default`
+pub fn read_input(path: Option<&Path>, default: &Path) -> Result<String> {
+    if let Some(path) = path {
+        return read_path(path);
+    }
+
+    if let Some(path) = env::var_os(INPUT_ENV).filter(|path| !path.is_empty()) {
+        return read_path(Path::new(&path))
+            .with_context(|| format!("Input path set by {INPUT_ENV}"));
+    }
+
+    if default.exists() {
+        return read_path(default);
+    }
+
+    Err(no_input(default))
+}
+
+/// Opens the puzzle input found like [`read_input`] for reading it line by
+/// line, without loading it all in memory
+pub fn open_input(path: Option<&Path>, default: &Path) -> Result<Box<dyn BufRead>> {
+    if let Some(path) = path {
+        return open_path(path);
+    }
+
+    if let Some(path) = env::var_os(INPUT_ENV).filter(|path| !path.is_empty()) {
+        return open_path(Path::new(&path))
+            .with_context(|| format!("Input path set by {INPUT_ENV}"));
+    }
+
+    if default.exists() {
+        return open_path(default);
+    }
+
+    Err(no_input(default))
+}
+
+/// Path the puzzle input is read from, found like [`read_input`], `None`
+/// when there is none
+pub fn input_path(path: Option<&Path>, default: &Path) -> Option<PathBuf> {
+    path.map(Path::to_path_buf)
+        .or_else(|| {
+            env::var_os(INPUT_ENV)
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from)
+        })
+        .or_else(|| default.exists().then(|| default.to_path_buf()))
+}
+
+fn no_input(default: &Path) -> anyhow::Error {
+    anyhow!(
+        "No input found: no input path was given, {INPUT_ENV} is not set and {} does not exist",
+        default.display()
+    )
+}
+
+/// Parses the command line arguments and reads the puzzle input they point
+/// to, see [`Args`] and [`read_input`]
+pub fn from_args(default: &Path) -> Result<(Args, String)> {
+    from_args_with(default, &[], &[])
+}
+
+/// [`from_args`] for a binary that also takes the `options` and `flags` of
+/// its day, see [`Args::parse_with`]
+pub fn from_args_with(default: &Path, options: &[&str], flags: &[&str]) -> Result<(Args, String)> {
+    let args = Args::parse_with(env::args_os().skip(1), options, flags)?;
+    let input = args.read_input(default)?;
+    Ok((args, input))
+}
+
+/// Command line arguments of a day's binary: `[--format text|json] [INPUT]`
+/// plus the day's own `--<option> VALUE` pairs and `--<flag>` switches
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    /// Input path, see [`read_input`]
+    pub path: Option<PathBuf>,
+    /// Whether a JSON report is printed instead of text
+    pub json: bool,
+    /// Values of the day's options in the order given, by option name without
+    /// the dashes
+    pub options: BTreeMap<String, Vec<String>>,
+    /// The day's flags that were given, without the dashes
+    pub flags: BTreeSet<String>,
+}
+
+impl Args {
+    /// Parses the arguments the binary was started with
+    pub fn from_env() -> Result<Self> {
+        Self::parse(env::args_os().skip(1))
+    }
+
+    /// Parses `args`, without the binary name
+    pub fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Self> {
+        Self::parse_with(args, &[], &[])
+    }
+
+    /// Parses `args` like [`Args::parse`], also accepting `--<name> VALUE` for
+    /// each name in `options` and `--<name>` for each name in `flags`
+    pub fn parse_with(
+        args: impl IntoIterator<Item = OsString>,
+        options: &[&str],
+        flags: &[&str],
+    ) -> Result<Self> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let name = arg.to_str().and_then(|arg| arg.strip_prefix("--"));
+            let option = name.filter(|name| options.contains(name));
+            let flag = name.filter(|name| flags.contains(name));
+
+            if let Some(name) = flag {
+                parsed.flags.insert(name.to_string());
+            } else if let Some(name) = option {
+                let value = args
+                    .next()
+                    .with_context(|| format!("--{name} expects a value"))?;
+                let value = value.into_string().map_err(|value| {
+                    anyhow!("Invalid value {} for --{name}", value.to_string_lossy())
+                })?;
+                parsed
+                    .options
+                    .entry(name.to_string())
+                    .or_default()
+                    .push(value);
+            } else if arg == "--format" {
+                let format = args.next().context("--format expects text or json")?;
+                parsed.json = match format.to_str() {
+                    Some("text") => false,
+                    Some("json") => true,
+                    _ => bail!(
+                        "Unknown format {}, expected text or json",
+                        format.to_string_lossy()
+                    ),
+                };
+            } else if name.is_some() {
+                bail!("Unknown option {}", arg.to_string_lossy());
+            } else if parsed.path.is_none() {
+                parsed.path = Some(PathBuf::from(arg));
+            } else {
+                bail!("Unexpected argument {}", arg.to_string_lossy());
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Value of the option `name` parsed as a `T`, `None` when not given. The
+    /// last value counts when the option is given several times
+    pub fn option<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.options
+            .get(name)
+            .and_then(|values| values.last())
+            .map(|value| parse_option(name, value))
+            .transpose()
+    }
+
+    /// Every value of the option `name` parsed as a `T`, in the order given
+    pub fn values<T>(&self, name: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.options
+            .get(name)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .map(|value| parse_option(name, value))
+            .collect()
+    }
+
+    /// Whether the flag `name` was given
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    /// Reads the puzzle input, see [`read_input`]
+    pub fn read_input(&self, default: &Path) -> Result<String> {
+        read_input(self.path.as_deref(), default)
+    }
+
+    /// Opens the puzzle input, see [`open_input`]
+    pub fn open_input(&self, default: &Path) -> Result<Box<dyn BufRead>> {
+        open_input(self.path.as_deref(), default)
+    }
+
+    /// Path the puzzle input is read from, see [`input_path`]
+    pub fn input_path(&self, default: &Path) -> Option<PathBuf> {
+        input_path(self.path.as_deref(), default)
+    }
+}
+
+fn parse_option<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| anyhow!("Invalid value {value} for --{name}: {e}"))
+}
+
+fn read_path(path: &Path) -> Result<String> {
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Could not read input from stdin")?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path)
+        .with_context(|| format!("Could not read input file {}", path.display()))
+}
+
+fn open_path(path: &Path) -> Result<Box<dyn BufRead>> {
+    if path == Path::new(STDIN) {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = fs::File::open(path)
+        .with_context(|| format!("Could not read input file {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
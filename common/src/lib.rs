@@ -0,0 +1,15 @@
+//! Shared building blocks for the Advent of Code 2022 days
+
+#![warn(missing_docs)]
+
+pub use diagnostic::Diagnostic;
+pub use solution::{Puzzle, Solution, Solver};
+
+mod diagnostic;
+pub mod generate;
+pub mod input;
+pub mod parse;
+pub mod report;
+mod solution;
+pub mod top;
+pub mod trace;
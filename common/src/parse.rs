@@ -0,0 +1,245 @@
+//! Splits puzzle inputs into numbered lines, blank-line separated groups,
+//! whitespace separated tokens, character grids and fixed-width columns. Every
+//! error is a [`Diagnostic`] pointing at the offending text
+
+use std::str::{FromStr, SplitWhitespace};
+
+use crate::Diagnostic;
+
+/// Line of the input together with its line number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1
+    pub number: usize,
+    /// Text of the line without its line ending
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Line `number` with the given `text`
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// Error pointing at `snippet`, a slice of the line
+    pub fn error(&self, message: impl Into<String>, snippet: &str) -> Diagnostic {
+        Diagnostic::new(message, self.text, snippet).on_line(self.number)
+    }
+
+    /// Error pointing at `len` characters starting at the character index
+    /// `column`, counted from 0
+    pub fn error_at_column(
+        &self,
+        message: impl Into<String>,
+        column: usize,
+        len: usize,
+    ) -> Diagnostic {
+        Diagnostic::at_column(message, self.text, column, len).on_line(self.number)
+    }
+
+    /// Error pointing just past the end of the line, for missing text
+    pub fn error_at_end(&self, message: impl Into<String>) -> Diagnostic {
+        Diagnostic::at_end(message, self.text).on_line(self.number)
+    }
+
+    /// Whether the line only has whitespace
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Whitespace separated tokens of the line
+    pub fn tokens(&self) -> Tokens<'a> {
+        Tokens {
+            line: *self,
+            words: self.text.split_whitespace(),
+        }
+    }
+
+    /// Parses `field`, a slice of the line, naming it `what` in the error
+    pub fn parse<T: FromStr>(&self, field: &str, what: &str) -> Result<T, Diagnostic> {
+        field
+            .parse()
+            .map_err(|_| self.error(format!("Invalid {what} \"{field}\""), field))
+    }
+
+    /// Splits `field`, a slice of the line, at the first `separator`. When it
+    /// is missing the error points past `field` with `message`
+    pub fn split_once(
+        &self,
+        field: &'a str,
+        separator: char,
+        message: impl Into<String>,
+    ) -> Result<(&'a str, &'a str), Diagnostic> {
+        field.split_once(separator).ok_or_else(|| {
+            let end = self.text[..offset(self.text, field) + field.len()]
+                .chars()
+                .count();
+            self.error_at_column(message, end, 1)
+        })
+    }
+
+    /// Cells of `width` characters each, the last one may be shorter
+    pub fn columns(&self, width: usize) -> Columns<'a> {
+        assert!(width > 0, "Columns have at least one character");
+        Columns {
+            rest: self.text,
+            width,
+        }
+    }
+}
+
+/// Byte offset of `field` in `text`, when it is one of its slices
+fn offset(text: &str, field: &str) -> usize {
+    let start = field.as_ptr() as usize;
+    let text_start = text.as_ptr() as usize;
+    if start >= text_start && start + field.len() <= text_start + text.len() {
+        start - text_start
+    } else {
+        text.find(field).unwrap_or(0)
+    }
+}
+
+/// Numbered lines of `input`, `\r\n` line endings included
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// Groups of consecutive lines separated by one or more blank lines. Blank
+/// lines at the start or end of the input do not make empty groups
+pub fn groups(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+
+    for line in lines(input) {
+        if line.is_blank() {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+        } else {
+            group.push(line);
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    groups
+}
+
+/// Rectangular grid with one `cell` per character, named `what` in errors.
+/// Every row must have the width of the first one
+pub fn grid<T>(
+    input: &str,
+    what: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, Diagnostic> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for line in lines(input) {
+        let row = line
+            .text
+            .chars()
+            .enumerate()
+            .map(|(column, c)| {
+                cell(c)
+                    .ok_or_else(|| line.error_at_column(format!("Invalid {what} {c:?}"), column, 1))
+            })
+            .collect::<Result<Vec<T>, Diagnostic>>()?;
+
+        if row.is_empty() {
+            return Err(line.error_at_end("Row is empty"));
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(line.error_at_end(format!(
+                    "Row has {} cells, expected {}",
+                    row.len(),
+                    first.len()
+                )));
+            }
+        }
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(Diagnostic::at_end("Grid is empty", ""));
+    }
+
+    Ok(rows)
+}
+
+/// Whitespace separated tokens of a [`Line`], read one at a time
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    line: Line<'a>,
+    words: SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    /// Next token, named `what` in the error when the line ends first
+    pub fn next_token(&mut self, what: &str) -> Result<&'a str, Diagnostic> {
+        self.words
+            .next()
+            .ok_or_else(|| self.line.error_at_end(format!("Missing {what}")))
+    }
+
+    /// Parses the next token, named `what` in the errors
+    pub fn parse_next<T: FromStr>(&mut self, what: &str) -> Result<T, Diagnostic> {
+        let token = self.next_token(what)?;
+        self.line.parse(token, what)
+    }
+
+    /// Reads the next token, which must be `keyword`
+    pub fn expect(&mut self, keyword: &str) -> Result<(), Diagnostic> {
+        let token = self.next_token(&format!("\"{keyword}\""))?;
+        if token != keyword {
+            return Err(self.line.error(format!("Expected \"{keyword}\""), token));
+        }
+        Ok(())
+    }
+
+    /// Fails if any token is left
+    pub fn finish(mut self) -> Result<(), Diagnostic> {
+        match self.words.next() {
+            Some(token) => Err(self.line.error(format!("Unexpected \"{token}\""), token)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.words.next()
+    }
+}
+
+/// Fixed-width cells of a [`Line`], see [`Line::columns`]
+#[derive(Debug, Clone)]
+pub struct Columns<'a> {
+    rest: &'a str,
+    width: usize,
+}
+
+impl<'a> Iterator for Columns<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let end = self
+            .rest
+            .char_indices()
+            .nth(self.width)
+            .map_or(self.rest.len(), |(end, _)| end);
+        let (cell, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cell)
+    }
+}
@@ -0,0 +1,45 @@
+//! Machine readable reports of a solved puzzle
+
+use std::process::exit;
+
+use anyhow::{bail, Result};
+use serde_json::{Map, Value};
+
+use crate::Solution;
+
+/// Solves `parts` of the puzzle and collects their answers along with the
+/// day's [`Solution::details`] into a JSON object
+///
+/// ```json
+/// { "day": 6, "title": "Tuning Trouble", "part1": 7, "part2": 19, "details": { "length": 30 } }
+/// ```
+pub fn report<S: Solution>(input: &S::Input, parts: &[u8]) -> Result<Value> {
+    let mut report = Map::new();
+    report.insert("day".to_string(), S::DAY.into());
+    report.insert("title".to_string(), S::TITLE.into());
+
+    for part in parts {
+        let answer = match part {
+            1 => serde_json::to_value(S::part1(input)?)?,
+            2 => serde_json::to_value(S::part2(input)?)?,
+            _ => bail!("Invalid part {part}, expected 1 or 2"),
+        };
+        report.insert(format!("part{part}"), answer);
+    }
+
+    report.insert("details".to_string(), S::details(input));
+
+    Ok(Value::Object(report))
+}
+
+/// Prints the report of both parts to stdout, exiting with an error if a part
+/// could not be solved
+pub fn print<S: Solution>(input: &S::Input) {
+    match report::<S>(input, &[1, 2]) {
+        Ok(report) => println!("{report:#}"),
+        Err(e) => {
+            eprintln!("{e:#}");
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::{any::Any, fmt::Display, marker::PhantomData};
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{report::report, Diagnostic};
+
+/// A day's puzzle, split into parsing the input and solving each part
+pub trait Solution {
+    /// Day of the advent calendar
+    const DAY: u8;
+
+    /// Title of the puzzle
+    const TITLE: &'static str;
+
+    /// Input once parsed, shared by both parts
+    type Input: 'static;
+
+    /// Answer to the first part
+    type Answer1: Display + Serialize;
+    /// Answer to the second part
+    type Answer2: Display + Serialize;
+
+    /// Parses the raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic>;
+
+    /// Solves the first part of the puzzle
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    /// Solves the second part of the puzzle
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Intermediate data about the input, reported next to the answers in the
+    /// JSON output
+    fn details(_input: &Self::Input) -> Value {
+        Value::Null
+    }
+}
+
+/// Object safe view of a [`Solution`], so days with different input and
+/// answer types can be stored and run together
+pub trait Puzzle: Sync {
+    /// See [`Solution::DAY`]
+    fn day(&self) -> u8;
+
+    /// See [`Solution::TITLE`]
+    fn title(&self) -> &'static str;
+
+    /// Parses the raw puzzle input into the day's [`Solution::Input`]
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Solves `part` of the puzzle with an input returned by [`Puzzle::parse`]
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String>;
+
+    /// Solves `parts` of the puzzle into a JSON report, see [`report`]
+    ///
+    /// [`report`]: crate::report::report
+    fn report(&self, input: &dyn Any, parts: &[u8]) -> Result<Value>;
+}
+
+/// Adapts a [`Solution`] into a [`Puzzle`]
+pub struct Solver<S>(PhantomData<fn() -> S>);
+
+impl<S> Solver<S> {
+    /// Creates the adapter, usable in `static` items
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Solver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Puzzle for Solver<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String> {
+        let input = downcast::<S>(input)?;
+
+        match part {
+            1 => Ok(S::part1(input)?.to_string()),
+            2 => Ok(S::part2(input)?.to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+
+    fn report(&self, input: &dyn Any, parts: &[u8]) -> Result<Value> {
+        report::<S>(downcast::<S>(input)?, parts)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("Input was not parsed by day {}", S::DAY))
+}
@@ -0,0 +1,107 @@
+//! Keeping the `k` largest values of a sequence without sorting all of it
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// What happened to a value pushed into a [`TopK`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pushed<T> {
+    /// The value was kept and nothing was dropped
+    Kept,
+    /// The value was kept, pushing out the returned smallest value
+    Replaced(T),
+    /// The value is not larger than any kept value and is returned
+    Rejected(T),
+}
+
+/// The `k` largest values pushed so far, kept in a bounded min-heap so each
+/// push takes O(log k).
+///
+/// A value equal to the smallest kept one is rejected, so among equal values
+/// the first pushed are kept
+#[derive(Debug, Clone)]
+pub struct TopK<T: Ord> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    /// Empty collector keeping the `k` largest values
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            // Large `k` are usually upper bounds, don't reserve for them
+            heap: BinaryHeap::with_capacity(k.min(1024) + 1),
+        }
+    }
+
+    /// Number of values kept at most
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Number of values kept
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Whether no value is kept
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Smallest kept value, the one a larger value would replace
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(value)| value)
+    }
+
+    /// Keeps `value` if it is among the `k` largest values pushed so far
+    pub fn push(&mut self, value: T) -> Pushed<T> {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+            return Pushed::Kept;
+        }
+
+        match self.heap.peek_mut() {
+            Some(mut min) if value > min.0 => {
+                // The heap order is restored when `min` is dropped
+                Pushed::Replaced(std::mem::replace(&mut min.0, value))
+            }
+            _ => Pushed::Rejected(value),
+        }
+    }
+
+    /// Kept values, largest first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorted ascending by `Reverse`, so largest first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+/// Adds [`top_k`](TopKExt::top_k) to every iterator
+pub trait TopKExt: Iterator {
+    /// The `k` largest items, largest first, in O(n log k) time and O(k)
+    /// memory
+    fn top_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut top = TopK::new(k);
+        top.extend(self);
+        top.into_sorted_vec()
+    }
+}
+
+impl<I: Iterator> TopKExt for I {}
@@ -0,0 +1,149 @@
+//! Step by step events of the solvers, recorded as JSON lines to debug wrong
+//! answers and build visualizations
+
+use std::io::{self, BufWriter, Write};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+
+use crate::{Diagnostic, Solution};
+
+/// A day whose solver reports the steps it takes
+pub trait Trace: Solution {
+    /// Step of the solver. Events are serialized into JSON objects, so they
+    /// are enums tagged with `#[serde(tag = "event")]`
+    type Event: Serialize;
+
+    /// Parses the raw puzzle input like [`Solution::parse`], sending the steps
+    /// of parsing to `tracer`
+    fn parse_traced(
+        input: &str,
+        _tracer: &mut impl Tracer<Self::Event>,
+    ) -> Result<Self::Input, Diagnostic> {
+        Self::parse(input)
+    }
+
+    /// Solves `part` like [`Solution::part1`] and [`Solution::part2`], sending
+    /// each step to `tracer`. Solves without any steps unless overridden
+    fn solve_traced(
+        input: &Self::Input,
+        part: u8,
+        _tracer: &mut impl Tracer<Self::Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(Self::part1(input)?.to_string()),
+            2 => Ok(Self::part2(input)?.to_string()),
+            _ => Err(anyhow!("Invalid part {part}, expected 1 or 2")),
+        }
+    }
+}
+
+/// Receives the steps of a solver
+pub trait Tracer<E> {
+    /// Records one step
+    fn step(&mut self, event: E);
+}
+
+/// Tracer ignoring every step, used when solving normally
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoTrace;
+
+impl<E> Tracer<E> for NoTrace {
+    #[inline(always)]
+    fn step(&mut self, _event: E) {}
+}
+
+/// Keeps every step in memory, handy in tests
+impl<E> Tracer<E> for Vec<E> {
+    fn step(&mut self, event: E) {
+        self.push(event);
+    }
+}
+
+/// Line written for each step
+#[derive(Serialize)]
+struct Step<'a, E> {
+    step: usize,
+    #[serde(flatten)]
+    event: &'a E,
+}
+
+/// Writes each step as a line of JSON, numbered from 1:
+///
+/// ```text
+/// {"step":1,"event":"move","amount":1,"from":"2","to":"1"}
+/// ```
+///
+/// The first write error stops the recording and is returned by
+/// [`Recorder::finish`]
+pub struct Recorder<W: Write> {
+    writer: W,
+    steps: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Records into `writer`
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            steps: 0,
+            error: None,
+        }
+    }
+
+    /// Number of steps recorded so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Flushes the writer and returns the number of steps recorded
+    pub fn finish(mut self) -> io::Result<usize> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.writer.flush()?;
+        Ok(self.steps)
+    }
+}
+
+impl<E: Serialize, W: Write> Tracer<E> for Recorder<W> {
+    fn step(&mut self, event: E) {
+        if self.error.is_some() {
+            return;
+        }
+
+        self.steps += 1;
+        let step = Step {
+            step: self.steps,
+            event: &event,
+        };
+        if let Err(error) = serde_json::to_writer(&mut self.writer, &step)
+            .map_err(io::Error::from)
+            .and_then(|_| self.writer.write_all(b"\n"))
+        {
+            self.error = Some(error);
+        }
+    }
+}
+
+/// Answer of a traced run and the number of steps written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    /// Answer to the traced part
+    pub answer: String,
+    /// Number of steps written
+    pub steps: usize,
+}
+
+/// Parses `input` and solves `part` of `S`, writing every step to `writer`
+/// as JSON lines
+pub fn record<S: Trace>(input: &str, part: u8, writer: &mut dyn Write) -> Result<Recorded> {
+    let mut recorder = Recorder::new(BufWriter::new(writer));
+
+    let parsed = S::parse_traced(input, &mut recorder)?;
+    let answer = S::solve_traced(&parsed, part, &mut recorder)?;
+    let steps = recorder.finish().context("Could not write the trace")?;
+
+    Ok(Recorded { answer, steps })
+}
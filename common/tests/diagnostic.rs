@@ -0,0 +1,41 @@
+use aoc_common::Diagnostic;
+
+#[test]
+fn points_at_snippet() {
+    let line = "move x from 1 to 3";
+    let amount = line.split_whitespace().nth(1).unwrap();
+
+    let diagnostic = Diagnostic::new("Invalid amount", line, amount).on_line(12);
+    assert_eq!(
+        (diagnostic.line, diagnostic.column, diagnostic.len),
+        (12, 6, 1)
+    );
+
+    assert_eq!(
+        diagnostic.to_string(),
+        [
+            "Invalid amount",
+            "  --> line 12, column 6",
+            "   |",
+            "12 | move x from 1 to 3",
+            "   |      ^",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn points_past_end_of_line() {
+    let diagnostic = Diagnostic::at_end("Missing response", "A");
+    assert_eq!(
+        (diagnostic.line, diagnostic.column, diagnostic.len),
+        (1, 2, 1)
+    );
+}
+
+#[test]
+fn counts_columns_in_characters() {
+    let line = "é 12";
+    let number = &line[3..];
+    assert_eq!(Diagnostic::new("", line, number).column, 3);
+}
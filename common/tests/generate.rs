@@ -0,0 +1,38 @@
+use aoc_common::generate::Rng;
+
+#[test]
+fn same_seed_gives_same_numbers() {
+    let numbers = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..10).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(numbers(42), numbers(42));
+    assert_ne!(numbers(42), numbers(43));
+}
+
+#[test]
+fn stays_in_range() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1_000 {
+        assert!(rng.below(7) < 7);
+        assert!((10..=12).contains(&rng.range(10..=12)));
+    }
+    assert_eq!(rng.range(5..=5), 5);
+
+    let mut seen = [false; 3];
+    for _ in 0..100 {
+        seen[rng.below(3)] = true;
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+#[test]
+fn shuffle_keeps_items() {
+    let mut items: Vec<u32> = (0..50).collect();
+    Rng::new(3).shuffle(&mut items);
+    assert_ne!(items, (0..50).collect::<Vec<_>>());
+
+    items.sort_unstable();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+}
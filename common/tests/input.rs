@@ -0,0 +1,83 @@
+use std::{ffi::OsString, io::Read, path::Path};
+
+use aoc_common::input::{self, Args};
+
+#[test]
+fn parses_binary_arguments() {
+    let args = |args: &[&str]| Args::parse(args.iter().map(OsString::from));
+
+    assert_eq!(args(&[]).unwrap(), Args::default());
+    assert_eq!(
+        args(&["--format", "json", "input.txt"]).unwrap(),
+        Args {
+            path: Some("input.txt".into()),
+            json: true,
+            ..Args::default()
+        }
+    );
+    assert_eq!(
+        args(&["-", "--format", "text"]).unwrap(),
+        Args {
+            path: Some("-".into()),
+            json: false,
+            ..Args::default()
+        }
+    );
+    assert!(args(&["--format", "xml"]).is_err());
+    assert!(args(&["--format"]).is_err());
+    assert!(args(&["a.txt", "b.txt"]).is_err());
+    assert!(args(&["--top", "3"]).is_err());
+
+    // Unknown options are errors even before the path is given
+    let error = args(&["--top", "3", "input.txt"]).unwrap_err();
+    assert_eq!(error.to_string(), "Unknown option --top");
+}
+
+#[test]
+fn parses_day_options() {
+    let args =
+        |args: &[&str]| Args::parse_with(args.iter().map(OsString::from), &["top"], &["all"]);
+
+    let parsed = args(&["--top", "5", "input.txt"]).unwrap();
+    assert_eq!(parsed.path, Some("input.txt".into()));
+    assert_eq!(parsed.option::<usize>("top").unwrap(), Some(5));
+    assert_eq!(parsed.option::<usize>("bottom").unwrap(), None);
+
+    assert!(args(&["--top", "x"])
+        .unwrap()
+        .option::<usize>("top")
+        .is_err());
+    assert!(args(&["--top"]).is_err());
+    let error = args(&["--bottom", "2"]).unwrap_err();
+    assert_eq!(error.to_string(), "Unknown option --bottom");
+    let error = args(&["input.txt", "--tpo", "3"]).unwrap_err();
+    assert_eq!(error.to_string(), "Unknown option --tpo");
+
+    // Repeated options keep every value
+    let parsed = args(&["--top", "1", "--top", "2"]).unwrap();
+    assert_eq!(parsed.option::<usize>("top").unwrap(), Some(2));
+    assert_eq!(parsed.values::<usize>("top").unwrap(), [1, 2]);
+    assert!(parsed.values::<usize>("bottom").unwrap().is_empty());
+
+    let parsed = args(&["--all", "input.txt"]).unwrap();
+    assert!(parsed.flag("all"));
+    assert!(!parsed.flag("top"));
+    assert_eq!(parsed.path, Some("input.txt".into()));
+    assert!(!args(&[]).unwrap().flag("all"));
+}
+
+#[test]
+fn opens_input_for_streaming() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let missing = Path::new("missing.txt");
+
+    let mut streamed = String::new();
+    input::open_input(Some(&path), missing)
+        .unwrap()
+        .read_to_string(&mut streamed)
+        .unwrap();
+    assert_eq!(streamed, input::read_input(Some(&path), missing).unwrap());
+
+    assert!(input::open_input(Some(missing), missing).is_err());
+    assert_eq!(input::input_path(Some(&path), missing), Some(path));
+}
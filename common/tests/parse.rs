@@ -0,0 +1,79 @@
+use aoc_common::parse::{self, Line};
+
+#[test]
+fn groups_skip_repeated_blank_lines() {
+    let groups = parse::groups("\n1\n2\r\n\r\n\n3\n  \n");
+    let numbers: Vec<Vec<(usize, &str)>> = groups
+        .iter()
+        .map(|group| group.iter().map(|line| (line.number, line.text)).collect())
+        .collect();
+
+    assert_eq!(numbers, [vec![(2, "1"), (3, "2")], vec![(6, "3")]]);
+    assert!(parse::groups("").is_empty());
+}
+
+#[test]
+fn tokens_point_at_bad_values() {
+    let line = Line::new(4, "move x from 1");
+    let mut tokens = line.tokens();
+    tokens.expect("move").unwrap();
+    let error = tokens.parse_next::<u32>("amount").unwrap_err();
+    assert_eq!(error.message, "Invalid amount \"x\"");
+    assert_eq!((error.line, error.column, error.len), (4, 6, 1));
+
+    let mut tokens = line.tokens();
+    let error = tokens.expect("copy").unwrap_err();
+    assert_eq!((error.column, error.len), (1, 4));
+
+    let mut tokens = line.tokens();
+    tokens.nth(3);
+    let error = tokens.next_token("destination").unwrap_err();
+    assert_eq!(error.message, "Missing destination");
+    assert_eq!(error.column, 14);
+
+    let mut tokens = line.tokens();
+    tokens.next_token("verb").unwrap();
+    let error = tokens.finish().unwrap_err();
+    assert_eq!(
+        (error.column, error.message.as_str()),
+        (6, "Unexpected \"x\"")
+    );
+}
+
+#[test]
+fn split_once_points_past_the_field() {
+    let line = Line::new(1, "2-4,6-8");
+    let (first, second) = line.split_once(line.text, ',', "Missing comma").unwrap();
+    assert_eq!((first, second), ("2-4", "6-8"));
+    assert_eq!(line.split_once(second, '-', "").unwrap(), ("6", "8"));
+
+    let error = line.split_once(first, ':', "Missing colon").unwrap_err();
+    assert_eq!(error.column, 4);
+}
+
+#[test]
+fn grid_checks_cells_and_width() {
+    let digit = |c: char| c.to_digit(10);
+
+    assert_eq!(
+        parse::grid("12\n34\n", "digit", digit).unwrap(),
+        [vec![1, 2], vec![3, 4]]
+    );
+
+    let error = parse::grid("12\n3x\n", "digit", digit).unwrap_err();
+    assert_eq!(error.message, "Invalid digit 'x'");
+    assert_eq!((error.line, error.column), (2, 2));
+
+    let error = parse::grid("12\n345\n", "digit", digit).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+    assert!(parse::grid("12\n\n", "digit", digit).is_err());
+    assert!(parse::grid("", "digit", digit).is_err());
+}
+
+#[test]
+fn columns_have_fixed_width() {
+    let line = Line::new(1, "[A]     [C]");
+    let cells: Vec<&str> = line.columns(4).collect();
+    assert_eq!(cells, ["[A] ", "    ", "[C]"]);
+    assert_eq!(Line::new(1, "").columns(4).count(), 0);
+}
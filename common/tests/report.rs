@@ -0,0 +1,63 @@
+use anyhow::Result;
+use aoc_common::{report::report, Diagnostic, Solution};
+use serde_json::{json, Value};
+
+struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sum";
+
+    type Input = Vec<u32>;
+
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .map_err(|_| Diagnostic::new("Invalid number", line, line))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(input.iter().sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        Ok(format!("{:?}", input))
+    }
+
+    fn details(input: &Self::Input) -> Value {
+        json!({ "numbers": input.len() })
+    }
+}
+
+#[test]
+fn reports_answers_and_details() {
+    let input = Sum::parse("1\n2\n3").unwrap();
+
+    assert_eq!(
+        report::<Sum>(&input, &[1, 2]).unwrap(),
+        json!({
+            "day": 1,
+            "title": "Sum",
+            "part1": 6,
+            "part2": "[1, 2, 3]",
+            "details": { "numbers": 3 },
+        })
+    );
+}
+
+#[test]
+fn reports_only_requested_parts() {
+    let input = Sum::parse("4").unwrap();
+
+    let only_part2 = report::<Sum>(&input, &[2]).unwrap();
+    assert_eq!(only_part2.get("part1"), None);
+    assert_eq!(only_part2["part2"], "[4]");
+    assert!(report::<Sum>(&input, &[3]).is_err());
+}
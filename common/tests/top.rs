@@ -0,0 +1,38 @@
+use aoc_common::top::{Pushed, TopK, TopKExt};
+
+#[test]
+fn keeps_the_largest_values() {
+    let values = [5, 1, 9, 3, 7, 9, 2];
+
+    assert_eq!(values.iter().copied().top_k(3), [9, 9, 7]);
+    assert_eq!(values.iter().copied().top_k(10).len(), values.len());
+    assert!(values.iter().copied().top_k(0).is_empty());
+}
+
+#[test]
+fn reports_what_each_push_did() {
+    let mut top = TopK::new(2);
+
+    assert_eq!(top.push(4), Pushed::Kept);
+    assert_eq!(top.push(6), Pushed::Kept);
+    assert_eq!(top.min(), Some(&4));
+
+    assert_eq!(top.push(4), Pushed::Rejected(4));
+    assert_eq!(top.push(5), Pushed::Replaced(4));
+    assert_eq!((top.len(), top.k()), (2, 2));
+    assert_eq!(top.into_sorted_vec(), [6, 5]);
+}
+
+#[test]
+fn matches_sorting_everything() {
+    // Deterministic but scrambled values, with plenty of duplicates
+    let values: Vec<u32> = (0..10_000u32)
+        .map(|i| i.wrapping_mul(7919) % 1000)
+        .collect();
+
+    let mut sorted = values.clone();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted.truncate(2500);
+
+    assert_eq!(values.into_iter().top_k(2500), sorted);
+}
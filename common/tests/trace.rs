@@ -0,0 +1,141 @@
+use anyhow::Result;
+use aoc_common::{
+    trace::{record, NoTrace, Recorder, Trace, Tracer},
+    Diagnostic, Solution,
+};
+use serde::Serialize;
+
+struct Sum;
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event {
+    Add { number: u32, total: u32 },
+}
+
+fn sum(numbers: &[u32], tracer: &mut impl Tracer<Event>) -> u32 {
+    numbers.iter().fold(0, |total, number| {
+        tracer.step(Event::Add {
+            number: *number,
+            total: total + number,
+        });
+        total + number
+    })
+}
+
+impl Solution for Sum {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sum";
+
+    type Input = Vec<u32>;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Diagnostic> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .map_err(|_| Diagnostic::new("Invalid number", line, line))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(sum(input, &mut NoTrace))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(input.len() as u32)
+    }
+}
+
+impl Trace for Sum {
+    type Event = Event;
+
+    fn solve_traced(
+        input: &Self::Input,
+        part: u8,
+        tracer: &mut impl Tracer<Event>,
+    ) -> Result<String> {
+        match part {
+            1 => Ok(sum(input, tracer).to_string()),
+            _ => Ok(Self::part2(input)?.to_string()),
+        }
+    }
+}
+
+#[test]
+fn records_numbered_json_lines() {
+    let mut output = Vec::new();
+    let recorded = record::<Sum>("1\n2\n", 1, &mut output).unwrap();
+
+    assert_eq!((recorded.answer.as_str(), recorded.steps), ("3", 2));
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "{\"step\":1,\"event\":\"add\",\"number\":1,\"total\":1}\n\
+         {\"step\":2,\"event\":\"add\",\"number\":2,\"total\":3}\n"
+    );
+}
+
+#[test]
+fn parts_without_steps_still_solve() {
+    let mut output = Vec::new();
+    let recorded = record::<Sum>("1\n2\n", 2, &mut output).unwrap();
+
+    assert_eq!((recorded.answer.as_str(), recorded.steps), ("2", 0));
+    assert!(output.is_empty());
+}
+
+#[test]
+fn reports_parse_errors() {
+    let error = record::<Sum>("1\nx\n", 1, &mut Vec::new()).unwrap_err();
+    assert!(error.to_string().contains("Invalid number"));
+}
+
+#[test]
+fn collects_steps_in_memory() {
+    let mut steps = Vec::new();
+    Sum::solve_traced(&vec![4, 5], 1, &mut steps).unwrap();
+    assert_eq!(
+        steps,
+        [
+            Event::Add {
+                number: 4,
+                total: 4
+            },
+            Event::Add {
+                number: 5,
+                total: 9
+            }
+        ]
+    );
+}
+
+#[test]
+fn recorder_stops_at_the_first_write_error() {
+    struct Full;
+
+    impl std::io::Write for Full {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::StorageFull.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut recorder = Recorder::new(Full);
+    recorder.step(Event::Add {
+        number: 1,
+        total: 1,
+    });
+    recorder.step(Event::Add {
+        number: 1,
+        total: 2,
+    });
+    assert_eq!(recorder.steps(), 1);
+    assert!(recorder.finish().is_err());
+}